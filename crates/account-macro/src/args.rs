@@ -0,0 +1,44 @@
+use {
+    crate::{owner::AccountOwners, strategy::AccountStrategy},
+    syn::{Attribute, Error},
+};
+
+const ACCOUNT_IDENT_STR: &str = "account";
+
+/// Arguments of the `#[account(...)]` attributes.
+#[derive(Default)]
+pub struct AccountArgs {
+    /// Owners set with `owner = ...`.
+    pub owners: AccountOwners,
+    /// Strategy set with `strategy = ...`, detected from the derives when not set.
+    pub strategy: Option<AccountStrategy>,
+}
+
+impl TryFrom<&[Attribute]> for AccountArgs {
+    type Error = Error;
+
+    fn try_from(value: &[Attribute]) -> Result<Self, Self::Error> {
+        let mut args = AccountArgs::default();
+
+        for attr in value
+            .iter()
+            .filter(|attr| attr.path().is_ident(ACCOUNT_IDENT_STR))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("owner") {
+                    args.owners.parse_value(meta.value()?)
+                } else if meta.path.is_ident("strategy") {
+                    if args.strategy.is_some() {
+                        return Err(meta.error("The strategy is already set."));
+                    }
+                    args.strategy = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("Unknown account argument."))
+                }
+            })?;
+        }
+
+        Ok(args)
+    }
+}
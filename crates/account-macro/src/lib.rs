@@ -1,11 +1,23 @@
 use {
+    args::AccountArgs,
     keys::PrimaryKeys,
     quote::{quote, ToTokens},
-    syn::{parse_macro_input, punctuated::Punctuated, spanned::Spanned, Error, Item, Path, Token},
+    strategy::AccountStrategy,
+    syn::{
+        parse_macro_input, punctuated::Punctuated, spanned::Spanned, Error, Fields, Item, Path,
+        Token,
+    },
     typhoon_discriminator::DiscriminatorBuilder,
+    typhoon_syn::AccountVersion,
 };
 
+mod args;
 mod keys;
+mod owner;
+mod packed;
+mod strategy;
+mod tail;
+mod variants;
 
 fn has_derive(attrs: &[syn::Attribute], derive_name: &str) -> bool {
     attrs
@@ -23,15 +35,50 @@ fn has_derive(attrs: &[syn::Attribute], derive_name: &str) -> bool {
         })
 }
 
-#[proc_macro_derive(AccountState, attributes(key, no_space))]
+/// Derives `Packed` and `PackedState` for a struct stored in a packed little-endian layout.
+///
+/// A `{Name}View` type is generated with a `get_` accessor per field, decoding it in place,
+/// and a `set_` one when the view is over mutable data.
+///
+/// `AccountState` uses the `PackedStrategy` when this derive is in a later `#[derive]` attribute
+/// or with `#[account(strategy = packed)]`.
+#[proc_macro_derive(PackedState)]
+pub fn derive_packed(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as syn::ItemStruct);
+
+    packed::derive_packed(&item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives the account traits of a state, owned by the program or the owners set with
+/// `#[account(owner = ...)]`.
+///
+/// The data strategy is picked from the derives in a `#[derive]` attribute after this one, which
+/// are the only ones visible to it. Otherwise it is set with `#[account(strategy = ...)]`, one of
+/// `bytemuck` (default for structs), `checked`, `packed`, `tagged` (default for enums), `borsh`
+/// or `wincode`.
+///
+/// The last field can be marked `#[tail]` and declared as `[Entry; 0]` for entries stored after
+/// the state, the zero-length array keeping the state sized.
+#[proc_macro_derive(AccountState, attributes(account, key, no_space, tail, version))]
 pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let item = parse_macro_input!(item as Item);
-    let (attrs, name, generics, fields) = match item {
+    let unit_fields = Fields::Unit;
+    let (attrs, name, generics, fields, variants) = match item {
         Item::Struct(ref item_struct) => (
             &item_struct.attrs,
             &item_struct.ident,
             &item_struct.generics,
             &item_struct.fields,
+            None,
+        ),
+        Item::Enum(ref item_enum) => (
+            &item_enum.attrs,
+            &item_enum.ident,
+            &item_enum.generics,
+            &unit_fields,
+            Some(&item_enum.variants),
         ),
         _ => {
             return Error::new(item.span(), "Invalid account type")
@@ -40,45 +87,92 @@ pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
     };
 
+    let args = match AccountArgs::try_from(attrs.as_slice()) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let strategy = args
+        .strategy
+        .unwrap_or_else(|| AccountStrategy::detect(attrs, variants.is_some()));
+    if strategy == AccountStrategy::Tagged && variants.is_none() {
+        return Error::new(
+            name.span(),
+            "The `tagged` strategy is only available for enums.",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    let is_packed = strategy == AccountStrategy::Packed;
     let space_token = if attrs.iter().any(|a| a.path().is_ident("no_space")) {
         None
     } else {
+        let size = if is_packed {
+            quote!(<#name as Packed>::LEN)
+        } else {
+            quote!(core::mem::size_of::<#name>())
+        };
         Some(quote! {
             impl #name {
-                pub const SPACE: usize = <#name as Discriminator>::DISCRIMINATOR.len() + core::mem::size_of::<#name>();
+                pub const SPACE: usize = <#name as Discriminator>::DISCRIMINATOR.len() + #size;
             }
         })
     };
     let (_, ty_generics, where_clause) = generics.split_for_impl();
 
-    let keys = match PrimaryKeys::try_from(fields) {
-        Ok(fields) => fields,
+    let seeded_trait = match fields {
+        Fields::Unit => None,
+        fields => match PrimaryKeys::try_from(fields) {
+            Ok(keys) => Some(keys.split_for_impl(name)),
+            Err(err) => return err.to_compile_error().into(),
+        },
+    };
+    let owners = args.owners.check();
+    let version = match AccountVersion::try_from(attrs.as_slice()) {
+        Ok(version) => version,
         Err(err) => return err.to_compile_error().into(),
     };
-    let seeded_trait = keys.split_for_impl(name);
-    let discriminator = DiscriminatorBuilder::new(&name.to_string()).build();
-    let account_strategy = if has_derive(attrs, "SchemaRead") {
-        quote!(
-            WincodeStrategy<
-                {
-                    matches!(
-                <Self as wincode::SchemaRead<'static, wincode::config::DefaultConfig>>::TYPE_META,
-                wincode::TypeMeta::Static { zero_copy: true, .. }
-            )
-                },
-            >
-        )
-    } else if has_derive(attrs, "BorshDeserialize") {
-        quote!(BorshStrategy)
-    } else {
-        quote!(BytemuckStrategy)
+    let discriminator = DiscriminatorBuilder::new(&name.to_string())
+        .layout(version.version)
+        .build();
+    let migrate_trait = version
+        .migrate_from
+        .as_ref()
+        .zip(version.previous_version())
+        .map(|(previous, previous_version)| {
+            let previous_discriminator = DiscriminatorBuilder::new(&name.to_string())
+                .layout(previous_version)
+                .build();
+            quote! {
+                impl Migrate for #name #ty_generics #where_clause {
+                    type Previous = #previous;
+
+                    const PREVIOUS_DISCRIMINATOR: &'static [u8] = &[#(#previous_discriminator),*];
+                }
+            }
+        });
+    let tail_trait = match tail::tail_trait(name, generics, fields) {
+        Ok(tail_trait) => tail_trait,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let variants = match variants
+        .map(|variants| variants::StateVariants::try_new(name, attrs, generics, variants))
+        .transpose()
+    {
+        Ok(variants) => variants,
+        Err(err) => return err.to_compile_error().into(),
     };
+    let variant_accessors = variants.as_ref().map(|variants| variants.accessors());
+    let tagged_state = variants
+        .as_ref()
+        .filter(|_| strategy == AccountStrategy::Tagged)
+        .map(|variants| variants.tagged_state());
 
     quote! {
         impl CheckOwner for #name #ty_generics #where_clause {
             #[inline(always)]
             fn owned_by(owner: &Address) -> bool {
-                address_eq(owner, &crate::ID)
+                #owners
             }
         }
 
@@ -87,9 +181,17 @@ pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
 
         impl DataStrategy for #name #ty_generics #where_clause {
-            type Strategy = #account_strategy;
+            type Strategy = #strategy;
         }
 
+        #migrate_trait
+
+        #tail_trait
+
+        #tagged_state
+
+        #variant_accessors
+
         #space_token
 
         #seeded_trait
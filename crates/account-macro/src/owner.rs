@@ -0,0 +1,75 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{
+        bracketed,
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        Error, LitStr, Path, Token,
+    },
+};
+
+/// An allowed owner of the account.
+pub enum Owner {
+    /// A base58 encoded address.
+    Address(LitStr),
+    /// A type implementing `CheckProgramId`.
+    Program(Path),
+}
+
+impl Parse for Owner {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            input.parse().map(Owner::Address)
+        } else {
+            input.parse().map(Owner::Program)
+        }
+    }
+}
+
+impl Owner {
+    fn check(&self) -> TokenStream {
+        match self {
+            Owner::Address(address) => quote! {
+                address_eq(owner, &const { Address::from_str_const(#address) })
+            },
+            Owner::Program(program) => quote! {
+                <#program as CheckProgramId>::address_eq(owner)
+            },
+        }
+    }
+}
+
+/// Owners set with `#[account(owner = ...)]`, the current program when empty.
+#[derive(Default)]
+pub struct AccountOwners(Vec<Owner>);
+
+impl AccountOwners {
+    /// Generates the body of `CheckOwner::owned_by`.
+    pub fn check(&self) -> TokenStream {
+        if self.0.is_empty() {
+            return quote!(address_eq(owner, &crate::ID));
+        }
+
+        let checks = self.0.iter().map(Owner::check);
+        quote!(#(#checks)||*)
+    }
+
+    /// Parses the value of an `owner` argument, a single owner or a list of them.
+    pub fn parse_value(&mut self, input: ParseStream) -> syn::Result<()> {
+        if input.peek(syn::token::Bracket) {
+            let span = input.span();
+            let content;
+            bracketed!(content in input);
+            let list = Punctuated::<Owner, Token![,]>::parse_terminated(&content)?;
+            if list.is_empty() {
+                return Err(Error::new(span, "The owner list cannot be empty."));
+            }
+            self.0.extend(list);
+        } else {
+            self.0.push(input.parse()?);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use {
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote},
+    syn::{Error, Fields, ItemStruct},
+};
+
+pub fn derive_packed(item: &ItemStruct) -> Result<TokenStream, Error> {
+    let Fields::Named(fields) = &item.fields else {
+        return Err(Error::new_spanned(
+            item,
+            "PackedState can only be derived for structs with named fields.",
+        ));
+    };
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "PackedState cannot be derived for generic structs.",
+        ));
+    }
+
+    let name = &item.ident;
+    let vis = &item.vis;
+    let view = format_ident!("{name}View");
+
+    let mut offset = quote!(0);
+    let mut unpack_fields = Vec::new();
+    let mut pack_fields = Vec::new();
+    let mut getters = Vec::new();
+    let mut setters = Vec::new();
+
+    for field in &fields.named {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let start = offset.clone();
+        let end = quote!(#start + <#ty as Packed>::LEN);
+        let getter = format_ident!("get_{}", ident.as_ref().unwrap());
+        let setter = format_ident!("set_{}", ident.as_ref().unwrap());
+
+        unpack_fields.push(quote!(#ident: <#ty as Packed>::unpack(&src[#start..#end])?,));
+        pack_fields.push(quote!(<#ty as Packed>::pack(&self.#ident, &mut dst[#start..#end]);));
+        getters.push(quote! {
+            #[inline(always)]
+            pub fn #getter(&self) -> Result<#ty, ProgramError> {
+                <#ty as Packed>::unpack(&self.0[#start..#end])
+            }
+        });
+        setters.push(quote! {
+            #[inline(always)]
+            pub fn #setter(&mut self, value: #ty) {
+                <#ty as Packed>::pack(&value, &mut self.0[#start..#end]);
+            }
+        });
+
+        offset = end;
+    }
+
+    Ok(quote! {
+        impl Packed for #name {
+            const LEN: usize = #offset;
+
+            #[inline(always)]
+            fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+                if src.len() != Self::LEN {
+                    return Err(ErrorCode::InvalidDataLength.into());
+                }
+
+                Ok(#name {
+                    #(#unpack_fields)*
+                })
+            }
+
+            #[inline(always)]
+            fn pack(&self, dst: &mut [u8]) {
+                #(#pack_fields)*
+            }
+        }
+
+        #vis struct #view<B>(B);
+
+        impl<B: core::ops::Deref<Target = [u8]>> #view<B> {
+            #(#getters)*
+
+            #[inline(always)]
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0[..<#name as Packed>::LEN]
+            }
+
+            /// Decodes all the fields.
+            #[inline(always)]
+            pub fn unpack(&self) -> Result<#name, ProgramError> {
+                <#name as Packed>::unpack(self.as_bytes())
+            }
+        }
+
+        impl<B: core::ops::DerefMut<Target = [u8]>> #view<B> {
+            #(#setters)*
+        }
+
+        impl PackedState for #name {
+            type View<B: core::ops::Deref<Target = [u8]>> = #view<B>;
+
+            #[inline(always)]
+            fn view<B: core::ops::Deref<Target = [u8]>>(data: B) -> Result<Self::View<B>, ProgramError> {
+                if data.len() < <#name as Packed>::LEN {
+                    return Err(ErrorCode::InvalidDataLength.into());
+                }
+
+                Ok(#view(data))
+            }
+        }
+    })
+}
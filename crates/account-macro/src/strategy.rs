@@ -0,0 +1,84 @@
+use {
+    crate::has_derive,
+    proc_macro2::TokenStream,
+    quote::{quote, ToTokens},
+    syn::{
+        parse::{Parse, ParseStream},
+        Attribute, Error, Ident,
+    },
+};
+
+/// Strategy used to access the account data.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AccountStrategy {
+    Bytemuck,
+    Checked,
+    Packed,
+    Tagged,
+    Borsh,
+    Wincode,
+}
+
+impl Parse for AccountStrategy {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "bytemuck" => Ok(AccountStrategy::Bytemuck),
+            "checked" => Ok(AccountStrategy::Checked),
+            "packed" => Ok(AccountStrategy::Packed),
+            "tagged" => Ok(AccountStrategy::Tagged),
+            "borsh" => Ok(AccountStrategy::Borsh),
+            "wincode" => Ok(AccountStrategy::Wincode),
+            _ => Err(Error::new(
+                ident.span(),
+                "Unknown strategy, expected one of `bytemuck`, `checked`, `packed`, `tagged`, \
+                 `borsh` or `wincode`.",
+            )),
+        }
+    }
+}
+
+impl AccountStrategy {
+    /// Picks the strategy from the derives of the type.
+    ///
+    /// Only the derives in a `#[derive]` attribute after `AccountState` are visible here, the
+    /// others are expanded first and removed from the item. The strategy must be set with
+    /// `#[account(strategy = ...)]` when they come before.
+    pub fn detect(attrs: &[Attribute], is_enum: bool) -> Self {
+        if has_derive(attrs, "SchemaRead") {
+            AccountStrategy::Wincode
+        } else if has_derive(attrs, "BorshDeserialize") {
+            AccountStrategy::Borsh
+        } else if has_derive(attrs, "PackedState") {
+            AccountStrategy::Packed
+        } else if has_derive(attrs, "CheckedBitPattern") {
+            AccountStrategy::Checked
+        } else if is_enum {
+            AccountStrategy::Tagged
+        } else {
+            AccountStrategy::Bytemuck
+        }
+    }
+}
+
+impl ToTokens for AccountStrategy {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AccountStrategy::Bytemuck => quote!(BytemuckStrategy),
+            AccountStrategy::Checked => quote!(CheckedBytemuckStrategy),
+            AccountStrategy::Packed => quote!(PackedStrategy),
+            AccountStrategy::Tagged => quote!(TaggedStrategy),
+            AccountStrategy::Borsh => quote!(BorshStrategy),
+            AccountStrategy::Wincode => quote!(
+                WincodeStrategy<
+                    {
+                        matches!(
+                    <Self as wincode::SchemaRead<'static, wincode::config::DefaultConfig>>::TYPE_META,
+                    wincode::TypeMeta::Static { zero_copy: true, .. }
+                )
+                    },
+                >
+            ),
+        });
+    }
+}
@@ -0,0 +1,68 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{spanned::Spanned, Error, Expr, Fields, Generics, Ident, Lit, Type},
+};
+
+const TAIL_IDENT_STR: &str = "tail";
+
+/// Generates the `Tail` implementation when a field is marked with `#[tail]`.
+///
+/// The tail field must be the last field and declared as `[Entry; 0]` so the state
+/// keeps a fixed size while the entries follow it in the account data. A `[Entry]` field
+/// would make the state unsized, which can't be `Copy` nor `Pod` to be read in place.
+pub fn tail_trait(
+    name: &Ident,
+    generics: &Generics,
+    fields: &Fields,
+) -> Result<Option<TokenStream>, Error> {
+    let mut tail_fields = fields.iter().enumerate().filter(|(_, field)| {
+        field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident(TAIL_IDENT_STR))
+    });
+
+    let Some((index, field)) = tail_fields.next() else {
+        return Ok(None);
+    };
+
+    if let Some((_, field)) = tail_fields.next() {
+        return Err(Error::new(
+            field.span(),
+            "Only one field can be marked as tail.",
+        ));
+    }
+
+    if index + 1 != fields.len() {
+        return Err(Error::new(
+            field.span(),
+            "The tail field must be the last field.",
+        ));
+    }
+
+    let entry = match &field.ty {
+        Type::Array(array) if is_zero(&array.len) => &array.elem,
+        ty => {
+            return Err(Error::new(
+                ty.span(),
+                "The tail field must be a zero-length array, e.g. `[Entry; 0]`, to keep the state sized.",
+            ))
+        }
+    };
+
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl Tail for #name #ty_generics #where_clause {
+            type Entry = #entry;
+        }
+    }))
+}
+
+fn is_zero(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(&lit.lit, Lit::Int(int) if int.base10_digits() == "0"),
+        _ => false,
+    }
+}
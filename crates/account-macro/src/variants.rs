@@ -0,0 +1,185 @@
+use {
+    heck::ToSnakeCase,
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote},
+    syn::{
+        punctuated::Punctuated, Attribute, Error, Fields, Generics, Ident, Path, Token, Variant,
+    },
+};
+
+/// Variants of an account state declared as a `#[repr(u8)]` enum.
+pub struct StateVariants<'a> {
+    name: &'a Ident,
+    generics: &'a Generics,
+    variants: &'a Punctuated<Variant, Token![,]>,
+}
+
+impl<'a> StateVariants<'a> {
+    pub fn try_new(
+        name: &'a Ident,
+        attrs: &[Attribute],
+        generics: &'a Generics,
+        variants: &'a Punctuated<Variant, Token![,]>,
+    ) -> Result<Self, Error> {
+        let is_repr_u8 = attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("repr"))
+            .filter_map(|attr| {
+                attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .any(|path| path.is_ident("u8"));
+
+        if !is_repr_u8 {
+            return Err(Error::new(
+                name.span(),
+                "Account enums must be `#[repr(u8)]`.",
+            ));
+        }
+
+        if variants.is_empty() {
+            return Err(Error::new(
+                name.span(),
+                "Account enums must have at least one variant.",
+            ));
+        }
+
+        Ok(StateVariants {
+            name,
+            generics,
+            variants,
+        })
+    }
+
+    /// Generates the `is_*`, `as_*` and `as_*_mut` accessors of each variant.
+    pub fn accessors(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let accessors = self.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let snake_name = ident.to_string().to_snake_case();
+            let is_ident = format_ident!("is_{snake_name}");
+            let is_accessor = quote! {
+                #[inline(always)]
+                pub fn #is_ident(&self) -> bool {
+                    matches!(self, Self::#ident { .. })
+                }
+            };
+
+            let Fields::Unnamed(fields) = &variant.fields else {
+                return is_accessor;
+            };
+            if fields.unnamed.len() != 1 {
+                return is_accessor;
+            }
+
+            let ty = &fields.unnamed[0].ty;
+            let as_ident = format_ident!("as_{snake_name}");
+            let as_mut_ident = format_ident!("as_{snake_name}_mut");
+
+            quote! {
+                #is_accessor
+
+                #[inline(always)]
+                #[allow(unreachable_patterns)]
+                pub fn #as_ident(&self) -> Option<&#ty> {
+                    match self {
+                        Self::#ident(value) => Some(value),
+                        _ => None,
+                    }
+                }
+
+                #[inline(always)]
+                #[allow(unreachable_patterns)]
+                pub fn #as_mut_ident(&mut self) -> Option<&mut #ty> {
+                    match self {
+                        Self::#ident(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        });
+
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#accessors)*
+            }
+        }
+    }
+
+    /// Generates the `TaggedState` implementation used to read the enum in place.
+    pub fn tagged_state(&self) -> TokenStream {
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let mut previous_tag = None;
+        let tags = self.variants.iter().map(|variant| {
+            let tag = match (&variant.discriminant, &previous_tag) {
+                (Some((_, expr)), _) => quote!((#expr)),
+                (None, Some(previous)) => quote!((#previous + 1)),
+                (None, None) => quote!(0),
+            };
+            previous_tag = Some(tag.clone());
+            tag
+        });
+        let tags: Vec<_> = tags.collect();
+
+        let writes = self.variants.iter().zip(&tags).map(|(variant, tag)| {
+            let ident = &variant.ident;
+            let bindings: Vec<_> = (0..variant.fields.len())
+                .map(|i| format_ident!("field_{i}"))
+                .collect();
+            let pattern = match &variant.fields {
+                Fields::Named(fields) => {
+                    let names = fields.named.iter().map(|field| &field.ident);
+                    quote!({ #(#names: #bindings),* })
+                }
+                Fields::Unnamed(_) => quote!((#(#bindings),*)),
+                Fields::Unit => quote!(),
+            };
+
+            quote! {
+                Self::#ident #pattern => write_tagged_variant(
+                    data,
+                    #tag,
+                    &[#(tagged_field_bytes(#bindings)),*],
+                ),
+            }
+        });
+
+        let payloads = self
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .map(|field| {
+                let ty = &field.ty;
+                quote!(assert_tagged_payload::<#ty>();)
+            });
+
+        quote! {
+            const _: () = {
+                #(#payloads)*
+                assert!(
+                    core::mem::align_of::<#name #ty_generics>() == 1,
+                    "Zero-copy account enums must have an alignment of 1."
+                );
+            };
+
+            unsafe impl #impl_generics TaggedState for #name #ty_generics #where_clause {
+                #[inline(always)]
+                fn is_valid_tag(tag: u8) -> bool {
+                    const TAGS: &[u8] = &[#(#tags),*];
+                    TAGS.contains(&tag)
+                }
+
+                #[inline(always)]
+                fn write_tagged(&self, data: &mut [u8]) {
+                    match self {
+                        #(#writes)*
+                    }
+                }
+            }
+        }
+    }
+}
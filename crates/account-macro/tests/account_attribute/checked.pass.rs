@@ -0,0 +1,80 @@
+use {
+    bytemuck::{CheckedBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    solana_program_error::ProgramError,
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        Accessor, CheckOwner, CheckedBytemuckStrategy, DataStrategy, Discriminator, MutAccessor,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(CheckedBitPattern, NoUninit, Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+pub enum Status {
+    Open,
+    Closed,
+}
+
+#[derive(AccountState)]
+#[derive(CheckedBitPattern, NoUninit, Clone, Copy)]
+#[repr(C)]
+pub struct Market {
+    pub price: [u8; 8],
+    pub status: Status,
+    pub is_paused: bool,
+}
+
+#[derive(CheckedBitPattern, NoUninit, Clone, Copy, AccountState)]
+#[account(strategy = checked)]
+#[repr(C)]
+pub struct Position {
+    pub status: Status,
+    pub is_long: bool,
+}
+
+#[derive(CheckedBitPattern, NoUninit, Clone, Copy)]
+#[derive(AccountState)]
+#[account(strategy = checked)]
+#[repr(C)]
+pub struct Switch {
+    pub is_on: bool,
+}
+
+fn assert_checked<T: DataStrategy<Strategy = CheckedBytemuckStrategy>>() {}
+
+fn main() {
+    assert_checked::<Market>();
+    assert_checked::<Position>();
+    assert_checked::<Switch>();
+    assert_eq!(Position::SPACE, Position::DISCRIMINATOR.len() + 2);
+
+    assert_eq!(
+        <CheckedBytemuckStrategy as Accessor<Position>>::access(&[0, 2]).err(),
+        Some(ProgramError::InvalidAccountData)
+    );
+    assert!(<CheckedBytemuckStrategy as Accessor<Switch>>::access(&[1]).unwrap().is_on);
+
+
+    let mut data = [0u8; 10];
+    {
+        let market =
+            <CheckedBytemuckStrategy as MutAccessor<Market>>::access_mut(&mut data).unwrap();
+        market.status = Status::Closed;
+        market.is_paused = true;
+    }
+
+    let market = <CheckedBytemuckStrategy as Accessor<Market>>::access(&data).unwrap();
+    assert_eq!(market.status, Status::Closed);
+    assert!(market.is_paused);
+
+    data[8] = 2;
+    assert_eq!(
+        <CheckedBytemuckStrategy as Accessor<Market>>::access(&data).err(),
+        Some(ProgramError::InvalidAccountData)
+    );
+}
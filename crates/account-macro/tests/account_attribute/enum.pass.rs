@@ -0,0 +1,77 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        assert_tagged_payload, tagged_field_bytes, write_tagged_variant, Accessor, CheckOwner,
+        DataStrategy, Discriminator, MutAccessor, TaggedState, TaggedStrategy,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(Pod, Zeroable, Copy, Clone)]
+#[repr(C)]
+pub struct OpenOrder {
+    pub owner: Address,
+    pub price: [u8; 8],
+}
+
+#[derive(Pod, Zeroable, Copy, Clone)]
+#[repr(C)]
+pub struct FilledOrder {
+    pub amount: [u8; 8],
+}
+
+#[derive(AccountState, Copy, Clone)]
+#[repr(u8)]
+pub enum Order {
+    Open(OpenOrder),
+    Filled(FilledOrder) = 4,
+    Cancelled,
+}
+
+pub fn main() {
+    assert!(Order::is_valid_tag(0));
+    assert!(Order::is_valid_tag(4));
+    assert!(Order::is_valid_tag(5));
+    assert!(!Order::is_valid_tag(1));
+
+    let mut data = [0u8; core::mem::size_of::<Order>()];
+    data[0] = 4;
+    data[1] = 7;
+
+    let order = <TaggedStrategy as Accessor<Order>>::access(&data).unwrap();
+    assert!(order.is_filled());
+    assert!(order.as_open().is_none());
+    assert_eq!(order.as_filled().unwrap().amount[0], 7);
+
+    let mut order = <TaggedStrategy as MutAccessor<Order>>::access_mut(&mut data).unwrap();
+    order.payload_mut(Order::as_filled).unwrap().amount[1] = 3;
+    assert!(order.payload_mut(Order::as_open).is_none());
+    assert_eq!(order.as_filled().unwrap().amount[..2], [7, 3]);
+
+    order.set(Order::Open(OpenOrder {
+        owner: ID,
+        price: [9; 8],
+    }));
+    assert_eq!(order.as_open().unwrap().price, [9; 8]);
+
+    // The bytes of the larger variant are zeroed when switching to a smaller one.
+    order.set(Order::Filled(FilledOrder { amount: [1; 8] }));
+    assert_eq!(order.as_filled().unwrap().amount, [1; 8]);
+    assert_eq!(data[0], 4);
+    assert!(data[9..].iter().all(|byte| *byte == 0));
+
+    let mut order = <TaggedStrategy as MutAccessor<Order>>::access_mut(&mut data).unwrap();
+    order.set(Order::Cancelled);
+    assert!(order.is_cancelled());
+    assert_eq!(data[0], 5);
+    assert!(data[1..].iter().all(|byte| *byte == 0));
+
+    data[0] = 2;
+    assert!(<TaggedStrategy as Accessor<Order>>::access(&data).is_err());
+}
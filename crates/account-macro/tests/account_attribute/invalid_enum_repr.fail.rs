@@ -0,0 +1,9 @@
+use typhoon_account_macro::*;
+
+#[derive(AccountState, Copy, Clone)]
+pub enum Order {
+    Open,
+    Cancelled,
+}
+
+pub fn main() {}
@@ -0,0 +1,13 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    typhoon_account_macro::*,
+};
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[account(program = "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS")]
+pub struct PriceFeed {
+    pub price: u64,
+}
+
+pub fn main() {}
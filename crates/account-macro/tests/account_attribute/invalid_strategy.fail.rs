@@ -0,0 +1,20 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    typhoon_account_macro::*,
+};
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[account(strategy = zero_copy)]
+pub struct PriceFeed {
+    pub price: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[account(strategy = tagged)]
+pub struct Oracle {
+    pub price: u64,
+}
+
+pub fn main() {}
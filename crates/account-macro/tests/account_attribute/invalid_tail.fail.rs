@@ -0,0 +1,14 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    typhoon_account_macro::*,
+};
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct OrderBook {
+    #[tail]
+    pub orders: [u64; 0],
+    pub sequence: u64,
+}
+
+pub fn main() {}
@@ -0,0 +1,50 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{BytemuckStrategy, CheckOwner, CheckProgramId, DataStrategy, Discriminator},
+};
+
+const ORACLE_ID: Address = Address::from_str_const("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+const SIBLING_ID: Address = Address::new_from_array([1; 32]);
+
+pub struct SiblingProgram;
+
+impl CheckProgramId for SiblingProgram {
+    fn address_eq(program_id: &Address) -> bool {
+        address_eq(program_id, &SIBLING_ID)
+    }
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[account(owner = "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS")]
+pub struct PriceFeed {
+    pub price: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[account(owner = SiblingProgram)]
+pub struct SiblingState {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[account(owner = [SiblingProgram, "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS"])]
+pub struct SharedState {
+    pub value: u64,
+}
+
+pub fn main() {
+    assert!(PriceFeed::owned_by(&ORACLE_ID));
+    assert!(!PriceFeed::owned_by(&SIBLING_ID));
+
+    assert!(SiblingState::owned_by(&SIBLING_ID));
+    assert!(!SiblingState::owned_by(&ORACLE_ID));
+
+    assert!(SharedState::owned_by(&SIBLING_ID));
+    assert!(SharedState::owned_by(&ORACLE_ID));
+    assert!(!SharedState::owned_by(&Address::new_from_array([2; 32])));
+}
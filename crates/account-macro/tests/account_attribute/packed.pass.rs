@@ -0,0 +1,91 @@
+use {
+    solana_address::{address_eq, Address},
+    solana_program_error::ProgramError,
+    typhoon_account_macro::*,
+    typhoon_errors::ErrorCode,
+    typhoon_traits::{
+        Accessor, CheckOwner, DataStrategy, Discriminator, MutAccessor, Packed, PackedState,
+        PackedStrategy,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(AccountState)]
+#[derive(PackedState, Debug, PartialEq)]
+pub struct Vault {
+    pub authority: Address,
+    pub delegate: Option<Address>,
+    pub amount: u64,
+    pub is_frozen: bool,
+}
+
+// The derives expanded before `AccountState` are not visible to it, the strategy is explicit.
+#[derive(PackedState, AccountState)]
+#[account(strategy = packed)]
+pub struct Counter {
+    pub count: u64,
+    pub is_closed: bool,
+}
+
+#[derive(PackedState)]
+#[derive(AccountState)]
+#[account(strategy = packed)]
+pub struct Flag {
+    pub is_set: bool,
+}
+
+fn assert_packed<T: DataStrategy<Strategy = PackedStrategy>>() {}
+
+fn main() {
+    assert_packed::<Vault>();
+    assert_packed::<Counter>();
+    assert_packed::<Flag>();
+    assert_eq!(Counter::SPACE, Counter::DISCRIMINATOR.len() + 9);
+    assert_eq!(Flag::SPACE, Flag::DISCRIMINATOR.len() + 1);
+
+    let mut data = [0u8; 9];
+    <PackedStrategy as MutAccessor<Counter>>::access_mut(&mut data)
+        .unwrap()
+        .set_count(3);
+    assert_eq!(data[..8], 3u64.to_le_bytes());
+
+
+    assert_eq!(<Vault as Packed>::LEN, 32 + 36 + 8 + 1);
+    assert_eq!(Vault::SPACE, Vault::DISCRIMINATOR.len() + <Vault as Packed>::LEN);
+
+    let mut data = [0u8; 77];
+    {
+        let mut vault = <PackedStrategy as MutAccessor<Vault>>::access_mut(&mut data).unwrap();
+        vault.set_authority(Address::new_from_array([1; 32]));
+        vault.set_amount(42);
+        vault.set_is_frozen(true);
+    }
+
+    // The amount is stored unaligned, right after the `COption`.
+    assert_eq!(data[68..76], 42u64.to_le_bytes());
+
+    let vault = <PackedStrategy as Accessor<Vault>>::access(&data).unwrap();
+    assert_eq!(vault.get_delegate(), Ok(None));
+    assert_eq!(vault.get_amount(), Ok(42));
+    assert_eq!(
+        vault.unpack(),
+        Ok(Vault {
+            authority: Address::new_from_array([1; 32]),
+            delegate: None,
+            amount: 42,
+            is_frozen: true,
+        })
+    );
+
+    data[76] = 2;
+    let vault = <PackedStrategy as Accessor<Vault>>::access(&data).unwrap();
+    assert_eq!(vault.get_is_frozen(), Err(ProgramError::InvalidAccountData));
+    assert_eq!(
+        <PackedStrategy as Accessor<Vault>>::access(&data[..10]).err(),
+        Some(ErrorCode::InvalidDataLength.into())
+    );
+}
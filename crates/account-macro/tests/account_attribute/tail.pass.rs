@@ -0,0 +1,36 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit, Pod, Zeroable},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, Tail},
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(Pod, Zeroable, Copy, Clone)]
+#[repr(C)]
+pub struct Order {
+    pub price: u64,
+    pub amount: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct OrderBook {
+    pub authority: Address,
+    pub sequence: u64,
+    #[tail]
+    pub orders: [Order; 0],
+}
+
+fn entry_size<T: Tail>() -> usize {
+    core::mem::size_of::<T::Entry>()
+}
+
+pub fn main() {
+    assert_eq!(entry_size::<OrderBook>(), 16);
+    assert_eq!(OrderBook::SPACE, 8 + 32 + 8);
+}
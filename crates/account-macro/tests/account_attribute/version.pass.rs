@@ -0,0 +1,41 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, Migrate},
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct CounterV1 {
+    pub count: u32,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[version(2, migrate_from = CounterV1)]
+pub struct Counter {
+    pub count: u64,
+}
+
+impl From<CounterV1> for Counter {
+    fn from(value: CounterV1) -> Self {
+        Counter {
+            count: value.count as u64,
+        }
+    }
+}
+
+pub fn main() {
+    assert_eq!(Counter::DISCRIMINATOR[4], 2);
+    assert_eq!(Counter::PREVIOUS_DISCRIMINATOR[4], 1);
+    assert_eq!(
+        Counter::DISCRIMINATOR[..4],
+        Counter::PREVIOUS_DISCRIMINATOR[..4]
+    );
+}
@@ -0,0 +1,77 @@
+use {
+    crate::{FromAccountInfo, FromRaw, Mut, ReadableAccount},
+    core::{cell::Cell, marker::PhantomData},
+    solana_account_view::AccountView,
+    typhoon_errors::Error,
+};
+
+/// Account whose checks are deferred until it is loaded.
+///
+/// The checks of `T` run on the first `load` (or `load_mut` for `Mut<Lazy<T>>`) and are
+/// skipped by the following ones, so branches that never touch the account don't pay for them.
+pub struct Lazy<'a, T> {
+    info: &'a AccountView,
+    is_checked: Cell<bool>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for Lazy<'a, T> {
+    #[inline(always)]
+    fn try_from_info(info: &'a AccountView) -> Result<Self, Error> {
+        Ok(Lazy::from_raw(info))
+    }
+}
+
+impl<'a, T> Lazy<'a, T>
+where
+    T: FromAccountInfo<'a> + FromRaw<'a>,
+{
+    /// Checks the account as `T` the first time it is called and returns it.
+    #[inline(always)]
+    pub fn load(&self) -> Result<T, Error> {
+        if self.is_checked.get() {
+            return Ok(T::from_raw(self.info));
+        }
+
+        let account = T::try_from_info(self.info)?;
+        self.is_checked.set(true);
+        Ok(account)
+    }
+}
+
+impl<'a, T> Mut<Lazy<'a, T>>
+where
+    T: FromAccountInfo<'a> + FromRaw<'a> + ReadableAccount,
+{
+    /// Checks the account as `T` the first time it is called and returns it as writable.
+    #[inline(always)]
+    pub fn load_mut(&self) -> Result<Mut<T>, Error> {
+        self.0.load().map(Mut)
+    }
+}
+
+impl<'a, T> From<Lazy<'a, T>> for &'a AccountView {
+    #[inline(always)]
+    fn from(value: Lazy<'a, T>) -> Self {
+        value.info
+    }
+}
+
+impl<T> AsRef<AccountView> for Lazy<'_, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &AccountView {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for Lazy<'_, T> {}
+
+impl<'a, T> FromRaw<'a> for Lazy<'a, T> {
+    fn from_raw(info: &'a AccountView) -> Self {
+        Self {
+            info,
+            is_checked: Cell::new(false),
+            _phantom: PhantomData,
+        }
+    }
+}
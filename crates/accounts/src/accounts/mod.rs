@@ -1,15 +1,19 @@
 mod account;
+mod lazy;
 mod mutable;
 mod program;
 mod signer;
 mod system;
 mod unchecked;
+mod versioned;
 
 pub use {
     account::*,
+    lazy::*,
     mutable::*,
     program::*,
     signer::{Signer, SignerCheck, UncheckedSigner},
     system::*,
     unchecked::*,
+    versioned::*,
 };
@@ -0,0 +1,104 @@
+use {
+    crate::{
+        discriminator_bytes_match, discriminator_matches, FromAccountInfo, FromRaw,
+        ReadableAccount, System,
+    },
+    core::marker::PhantomData,
+    pinocchio::hint::unlikely,
+    solana_account_view::AccountView,
+    solana_program_error::ProgramError,
+    typhoon_errors::{Error, ErrorCode},
+    typhoon_traits::{CheckOwner, CheckProgramId, Migrate},
+};
+
+/// Account stored with either the current or the previous layout of `T`.
+///
+/// Older versions are rejected, only the version directly before `T` is known to it.
+///
+/// Checks:
+/// * the discriminator matches `T::DISCRIMINATOR` or `T::PREVIOUS_DISCRIMINATOR`
+/// * the account is owned by an allowed owner of `T`
+pub struct VersionedAccount<'a, T>
+where
+    T: Migrate,
+{
+    info: &'a AccountView,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for VersionedAccount<'a, T>
+where
+    T: CheckOwner + Migrate,
+{
+    #[inline(always)]
+    fn try_from_info(info: &'a AccountView) -> Result<Self, Error> {
+        let data_len = info.data_len();
+        let is_current = data_len >= T::DISCRIMINATOR.len() && discriminator_matches::<T>(info);
+        let is_previous = data_len >= T::PREVIOUS_DISCRIMINATOR.len()
+            && discriminator_bytes_match(info, T::PREVIOUS_DISCRIMINATOR);
+
+        if unlikely(!is_current && !is_previous) {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        let owner = unsafe { info.owner() };
+
+        if unlikely(!T::owned_by(owner)) {
+            return Err(ProgramError::InvalidAccountOwner.into());
+        }
+
+        if unlikely(System::address_eq(owner) && info.lamports() == 0) {
+            return Err(ProgramError::UninitializedAccount.into());
+        }
+
+        Ok(VersionedAccount {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> VersionedAccount<'_, T>
+where
+    T: Migrate,
+{
+    /// Returns `true` if the account is already stored with the current layout.
+    #[inline(always)]
+    pub fn is_current(&self) -> bool {
+        self.info.data_len() >= T::DISCRIMINATOR.len() && discriminator_matches::<T>(self.info)
+    }
+}
+
+impl<'a, T> From<VersionedAccount<'a, T>> for &'a AccountView
+where
+    T: Migrate,
+{
+    #[inline(always)]
+    fn from(value: VersionedAccount<'a, T>) -> Self {
+        value.info
+    }
+}
+
+impl<T> AsRef<AccountView> for VersionedAccount<'_, T>
+where
+    T: Migrate,
+{
+    #[inline(always)]
+    fn as_ref(&self) -> &AccountView {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for VersionedAccount<'_, T> where T: Migrate {}
+
+impl<'a, T> FromRaw<'a> for VersionedAccount<'a, T>
+where
+    T: Migrate,
+{
+    fn from_raw(info: &'a AccountView) -> Self {
+        Self {
+            info,
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -6,7 +6,12 @@ use {solana_account_view::AccountView, typhoon_traits::Discriminator};
 /// - `>8` bytes: Standard slice comparison
 #[inline(always)]
 pub fn discriminator_matches<T: Discriminator>(info: &AccountView) -> bool {
-    let discriminator = T::DISCRIMINATOR;
+    discriminator_bytes_match(info, T::DISCRIMINATOR)
+}
+
+/// Discriminator matching against raw bytes, see [`discriminator_matches`].
+#[inline(always)]
+pub fn discriminator_bytes_match(info: &AccountView, discriminator: &[u8]) -> bool {
     let len = discriminator.len();
     let data_ptr = info.data_ptr();
 
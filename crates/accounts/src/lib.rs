@@ -1,17 +1,22 @@
 #![no_std]
 
-pub use {accounts::*, discriminator::*, programs::*};
+pub use {accounts::*, discriminator::*, programs::*, tail::*};
 use {
+    bytemuck::{AnyBitPattern, NoUninit},
     solana_account_view::{AccountView, Ref, RefMut},
     solana_address::Address,
     solana_program_error::ProgramError,
     typhoon_errors::Error,
-    typhoon_traits::{Accessor, DataStrategy, Discriminator, MutAccessor},
+    typhoon_traits::{
+        Accessor, DataStrategy, Discriminator, MutAccessor, PackedState, TaggedMut, TaggedState,
+        Tail,
+    },
 };
 
 mod accounts;
 mod discriminator;
 mod programs;
+mod tail;
 
 pub trait FromAccountInfo<'a>: Sized {
     fn try_from_info(info: &'a AccountView) -> Result<Self, Error>;
@@ -111,6 +116,26 @@ pub trait ReadableAccountData: AccountData {
             &data[Self::Data::DISCRIMINATOR.len()..],
         )
     }
+
+    #[inline(always)]
+    fn tail_data(&self) -> Result<TailRef<'_, Self::Data>, ProgramError>
+    where
+        Self::Data: Tail + AnyBitPattern,
+        <Self::Data as Tail>::Entry: AnyBitPattern,
+    {
+        TailRef::try_new(self.as_ref().try_borrow()?)
+    }
+
+    /// Returns a view decoding the fields of a packed state in place.
+    #[inline(always)]
+    fn packed_data(&self) -> Result<<Self::Data as PackedState>::View<Ref<'_, [u8]>>, ProgramError>
+    where
+        Self::Data: PackedState,
+    {
+        Self::Data::view(Ref::map(self.as_ref().try_borrow()?, |data| {
+            &data[Self::Data::DISCRIMINATOR.len()..]
+        }))
+    }
 }
 
 impl<T> ReadableAccountData for T where T: AccountData {}
@@ -129,6 +154,39 @@ pub trait WritableAccountData: AccountData + WritableAccount {
         })
         .map_err(|_| ProgramError::InvalidAccountData.into())
     }
+
+    #[inline(always)]
+    fn tail_data_mut(&self) -> Result<TailRefMut<'_, Self::Data>, Error>
+    where
+        Self::Data: Tail + NoUninit + AnyBitPattern,
+        <Self::Data as Tail>::Entry: NoUninit + AnyBitPattern,
+    {
+        TailRefMut::try_new(self.as_ref().try_borrow_mut()?).map_err(Into::into)
+    }
+
+    /// Returns a view decoding and encoding the fields of a packed state in place.
+    #[inline(always)]
+    fn packed_data_mut(&self) -> Result<<Self::Data as PackedState>::View<RefMut<'_, [u8]>>, Error>
+    where
+        Self::Data: PackedState,
+    {
+        Self::Data::view(RefMut::map(self.as_ref().try_borrow_mut()?, |data| {
+            &mut data[Self::Data::DISCRIMINATOR.len()..]
+        }))
+        .map_err(Into::into)
+    }
+
+    /// Returns a view updating a `#[repr(u8)]` enum state in place.
+    #[inline(always)]
+    fn tagged_data_mut(&self) -> Result<TaggedMut<RefMut<'_, [u8]>, Self::Data>, Error>
+    where
+        Self::Data: TaggedState,
+    {
+        TaggedMut::try_new(RefMut::map(self.as_ref().try_borrow_mut()?, |data| {
+            &mut data[Self::Data::DISCRIMINATOR.len()..]
+        }))
+        .map_err(Into::into)
+    }
 }
 
 impl<T> WritableAccountData for T where T: AccountData + WritableAccount {}
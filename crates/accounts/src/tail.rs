@@ -0,0 +1,159 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    core::{marker::PhantomData, mem::size_of},
+    solana_account_view::{Ref, RefMut},
+    solana_program_error::ProgramError,
+    typhoon_traits::{Discriminator, Tail},
+};
+
+/// Returns the number of entries stored after the state of `T` in `data`.
+#[inline(always)]
+fn tail_len<T>(data: &[u8]) -> Result<usize, ProgramError>
+where
+    T: Discriminator + Tail,
+{
+    let entry_size = size_of::<T::Entry>();
+    let tail_size = data
+        .len()
+        .checked_sub(T::DISCRIMINATOR.len() + size_of::<T>())
+        .ok_or(ProgramError::AccountDataTooSmall)?;
+
+    if entry_size == 0 || tail_size % entry_size != 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(tail_size / entry_size)
+}
+
+/// Checks that the state and its entries are aligned in `data`.
+#[inline(always)]
+fn check_alignment<T>(data: &[u8]) -> Result<(), ProgramError>
+where
+    T: Discriminator + Tail,
+{
+    let state = data[T::DISCRIMINATOR.len()..].as_ptr();
+    let entries = unsafe { state.add(size_of::<T>()) };
+
+    if state.align_offset(align_of::<T>()) != 0 || entries.align_offset(align_of::<T::Entry>()) != 0
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Borrowed account data made of a state followed by its entries.
+pub struct TailRef<'a, T> {
+    data: Ref<'a, [u8]>,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> TailRef<'a, T>
+where
+    T: Discriminator + Tail + AnyBitPattern,
+    T::Entry: AnyBitPattern,
+{
+    #[inline(always)]
+    pub(crate) fn try_new(data: Ref<'a, [u8]>) -> Result<Self, ProgramError> {
+        let len = tail_len::<T>(&data)?;
+        check_alignment::<T>(&data)?;
+
+        Ok(TailRef {
+            data,
+            len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no entries.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the state and its entries.
+    #[inline(always)]
+    pub fn split(&self) -> (&T, &[T::Entry]) {
+        // SAFETY: The length and the alignment were checked when the data was borrowed.
+        unsafe {
+            let state = self.data.as_ptr().add(T::DISCRIMINATOR.len());
+            (
+                &*(state as *const T),
+                core::slice::from_raw_parts(state.add(size_of::<T>()) as *const T::Entry, self.len),
+            )
+        }
+    }
+}
+
+/// Mutably borrowed account data made of a state followed by its entries.
+pub struct TailRefMut<'a, T> {
+    data: RefMut<'a, [u8]>,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> TailRefMut<'a, T>
+where
+    T: Discriminator + Tail + NoUninit + AnyBitPattern,
+    T::Entry: NoUninit + AnyBitPattern,
+{
+    #[inline(always)]
+    pub(crate) fn try_new(data: RefMut<'a, [u8]>) -> Result<Self, ProgramError> {
+        let len = tail_len::<T>(&data)?;
+        check_alignment::<T>(&data)?;
+
+        Ok(TailRefMut {
+            data,
+            len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no entries.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the state and its entries.
+    #[inline(always)]
+    pub fn split(&self) -> (&T, &[T::Entry]) {
+        // SAFETY: The length and the alignment were checked when the data was borrowed.
+        unsafe {
+            let state = self.data.as_ptr().add(T::DISCRIMINATOR.len());
+            (
+                &*(state as *const T),
+                core::slice::from_raw_parts(state.add(size_of::<T>()) as *const T::Entry, self.len),
+            )
+        }
+    }
+
+    /// Returns the mutable state and its entries.
+    #[inline(always)]
+    pub fn split_mut(&mut self) -> (&mut T, &mut [T::Entry]) {
+        // SAFETY: The length and the alignment were checked when the data was borrowed.
+        unsafe {
+            let state = self.data.as_mut_ptr().add(T::DISCRIMINATOR.len());
+            (
+                &mut *(state as *mut T),
+                core::slice::from_raw_parts_mut(
+                    state.add(size_of::<T>()) as *mut T::Entry,
+                    self.len,
+                ),
+            )
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use {
-    proc_macro2::TokenStream,
-    quote::{format_ident, quote},
-    syn::{parse_quote, punctuated::Punctuated, Expr, Ident, Token},
+    crate::paths,
+    proc_macro2::{Span, TokenStream},
+    quote::{format_ident, quote, quote_spanned},
+    syn::{parse_quote, punctuated::Punctuated, spanned::Spanned, Expr, Ident, Token},
     typhoon_syn::{
-        constraints::{ConstraintAddress, ConstraintAssert},
+        constraints::{
+            ConstraintAddress, ConstraintAssert, ConstraintComparison, ConstraintMintExtension,
+        },
         error,
         utils::{ContextExpr, SeedsExpr},
         InstructionAccount,
@@ -15,11 +18,14 @@ pub enum AccountType {
         is_ata: bool,
         mint: Option<Ident>,
         owner: Option<Expr>,
+        checks: Box<TokenChecks>,
     },
     Mint {
         decimals: Option<Expr>,
         authority: Option<Expr>,
         freeze_authority: Box<Option<Expr>>,
+        supply: Box<Option<ConstraintComparison>>,
+        extensions: Vec<ConstraintMintExtension>,
     },
     Other {
         space: Option<Expr>,
@@ -27,10 +33,22 @@ pub enum AccountType {
     },
 }
 
+/// Constraints only checked on existing token accounts.
+#[derive(Default)]
+pub struct TokenChecks {
+    pub delegate: Option<Expr>,
+    pub close_authority: Option<Expr>,
+    pub is_frozen: Option<Expr>,
+    pub amount: Option<ConstraintComparison>,
+    pub token_program: Option<Ident>,
+}
+
 #[derive(Default)]
 pub struct InitContext {
     pub is_init_if_needed: bool,
     pub payer: Option<Ident>,
+    pub system_program: Option<Ident>,
+    pub token_program: Option<Ident>,
 }
 
 #[derive(Default)]
@@ -41,6 +59,130 @@ pub struct PdaContext {
     pub program_id: Option<Expr>,
 }
 
+impl InitContext {
+    fn system_program(&self) -> Result<&Ident, syn::Error> {
+        self.system_program
+            .as_ref()
+            .ok_or_else(|| missing_program("System"))
+    }
+
+    fn token_program(&self) -> Result<&Ident, syn::Error> {
+        self.token_program
+            .as_ref()
+            .ok_or_else(|| missing_program("TokenProgram"))
+    }
+}
+
+fn missing_program(program: &str) -> syn::Error {
+    syn::Error::new(
+        Span::call_site(),
+        format!("One constraint requires including the `Program<{program}>` account."),
+    )
+}
+
+/// Generates the `MintExtensionConfig` of each extension set by `mint::extensions::...`.
+fn mint_extensions(
+    name: &Ident,
+    extensions: &[ConstraintMintExtension],
+) -> Result<Vec<TokenStream>, syn::Error> {
+    let mut transfer_fee = [None, None, None, None];
+    let mut close_authority = None;
+    let mut is_non_transferable = false;
+    let mut interest_bearing = [None, None];
+    let mut permanent_delegate = None;
+    let mut metadata_pointer = [None, None];
+
+    for extension in extensions {
+        match extension {
+            ConstraintMintExtension::TransferFeeConfigAuthority(expr) => {
+                transfer_fee[0] = Some(expr)
+            }
+            ConstraintMintExtension::WithdrawWithheldAuthority(expr) => {
+                transfer_fee[1] = Some(expr)
+            }
+            ConstraintMintExtension::TransferFeeBasisPoints(expr) => transfer_fee[2] = Some(expr),
+            ConstraintMintExtension::MaximumFee(expr) => transfer_fee[3] = Some(expr),
+            ConstraintMintExtension::CloseAuthority(expr) => close_authority = Some(expr),
+            ConstraintMintExtension::NonTransferable => is_non_transferable = true,
+            ConstraintMintExtension::InterestRateAuthority(expr) => {
+                interest_bearing[0] = Some(expr)
+            }
+            ConstraintMintExtension::InterestRate(expr) => interest_bearing[1] = Some(expr),
+            ConstraintMintExtension::PermanentDelegate(expr) => permanent_delegate = Some(expr),
+            ConstraintMintExtension::MetadataPointerAuthority(expr) => {
+                metadata_pointer[0] = Some(expr)
+            }
+            ConstraintMintExtension::MetadataAddress(expr) => metadata_pointer[1] = Some(expr),
+        }
+    }
+
+    let option = |expr: Option<&Expr>| match expr {
+        Some(expr) => quote!(Some(#expr)),
+        None => quote!(None),
+    };
+    let mut configs = Vec::new();
+
+    if transfer_fee.iter().any(Option::is_some) {
+        let [config_authority, withdraw_withheld_authority, basis_points, maximum_fee] =
+            transfer_fee;
+        let (Some(basis_points), Some(maximum_fee)) = (basis_points, maximum_fee) else {
+            error!(
+                name,
+                "`mint::extensions::transfer_fee::basis_points` and `mint::extensions::transfer_fee::maximum_fee` need to be specified."
+            );
+        };
+        let config_authority = option(config_authority);
+        let withdraw_withheld_authority = option(withdraw_withheld_authority);
+        configs.push(quote! {
+            MintExtensionConfig::TransferFeeConfig {
+                transfer_fee_config_authority: #config_authority,
+                withdraw_withheld_authority: #withdraw_withheld_authority,
+                transfer_fee_basis_points: #basis_points,
+                maximum_fee: #maximum_fee,
+            }
+        });
+    }
+
+    if let Some(close_authority) = close_authority {
+        configs.push(quote! {
+            MintExtensionConfig::MintCloseAuthority { close_authority: Some(#close_authority) }
+        });
+    }
+
+    if is_non_transferable {
+        configs.push(quote!(MintExtensionConfig::NonTransferable));
+    }
+
+    if interest_bearing.iter().any(Option::is_some) {
+        let [rate_authority, rate] = interest_bearing;
+        let Some(rate) = rate else {
+            error!(
+                name,
+                "`mint::extensions::interest_bearing::rate` needs to be specified."
+            );
+        };
+        let rate_authority = option(rate_authority);
+        configs.push(quote! {
+            MintExtensionConfig::InterestBearingConfig { rate_authority: #rate_authority, rate: #rate }
+        });
+    }
+
+    if let Some(delegate) = permanent_delegate {
+        configs.push(quote! {
+            MintExtensionConfig::PermanentDelegate { delegate: #delegate }
+        });
+    }
+
+    if metadata_pointer.iter().any(Option::is_some) {
+        let [authority, metadata_address] = metadata_pointer.map(option);
+        configs.push(quote! {
+            MintExtensionConfig::MetadataPointer { authority: #authority, metadata_address: #metadata_address }
+        });
+    }
+
+    Ok(configs)
+}
+
 /// Controls how `get_pda` derives the PDA address and bump.
 enum PdaMode {
     /// Derive both address and bump. Uses `create_program_address` when bump is known.
@@ -116,6 +258,20 @@ fn gen_address_guard(lhs: TokenStream, rhs: TokenStream, err: TokenStream) -> To
     }
 }
 
+fn gen_guard(condition: TokenStream, err: TokenStream) -> TokenStream {
+    quote! {
+        if hint::unlikely(!(#condition)) {
+            return Err(#err);
+        }
+    }
+}
+
+/// Attaches the failed constraint and the location of its declaration to an error.
+fn error_context(err: TokenStream, constraint: &str, span: Span) -> TokenStream {
+    let location = quote_spanned!(span=> file!(), line!());
+    quote!(#err.with_constraint(#constraint).with_location(#location))
+}
+
 impl AccountGenerator<'_> {
     pub fn needs_programs(&self) -> Vec<String> {
         let mut programs = Vec::with_capacity(3);
@@ -231,6 +387,7 @@ impl AccountGenerator<'_> {
         let Some(ref punctuated_keys) = ctx.keys else {
             error!(&self.account.name, "The seeds cannot be empty.");
         };
+        let pinocchio = paths::pinocchio();
 
         let seeds = if ctx.is_seeded {
             let account_ty = &self.account.inner_ty;
@@ -238,7 +395,7 @@ impl AccountGenerator<'_> {
             quote! {
                 let #seed_bytes_var = #account_ty::derive(#punctuated_keys);
                 let seeds = #seed_bytes_var.signer_seeds_with_bump(&bump);
-                let signer = CpiSigner::from(&seeds);
+                let signer = #pinocchio::cpi::Signer::from(&seeds);
             }
         } else {
             match punctuated_keys {
@@ -246,8 +403,11 @@ impl AccountGenerator<'_> {
                     let (bindings, temps) = seed_temp_bindings(&self.account.name, punctuated);
                     quote! {
                         #bindings
-                        let seeds = seeds!(#(#temps),*, &bump);
-                        let signer = CpiSigner::from(&seeds);
+                        let seeds = [
+                            #(#pinocchio::cpi::Seed::from(#temps),)*
+                            #pinocchio::cpi::Seed::from(&bump),
+                        ];
+                        let signer = #pinocchio::cpi::Signer::from(&seeds);
                     }
                 }
                 SeedsExpr::Single(expr) => {
@@ -258,13 +418,16 @@ impl AccountGenerator<'_> {
                     quote! {
                         let expr = #expr;
                         let expr_len = expr.len();
-                        let mut buffer = [bytes::UNINIT_SEED; MAX_SEEDS];
+                        let mut buffer = [
+                            const { ::core::mem::MaybeUninit::<#pinocchio::cpi::Seed>::uninit() };
+                            #pinocchio::address::MAX_SEEDS
+                        ];
                         for (uninit_byte, &src_byte) in buffer[..expr_len].iter_mut().zip(&expr) {
-                            uninit_byte.write(Seed::from(src_byte));
+                            uninit_byte.write(#pinocchio::cpi::Seed::from(src_byte));
                         }
-                        buffer[expr_len].write(Seed::from(&bump));
+                        buffer[expr_len].write(#pinocchio::cpi::Seed::from(&bump));
 
-                        let signer = CpiSigner::from(unsafe { core::slice::from_raw_parts(buffer.as_ptr() as *const Seed, expr_len + 1) });
+                        let signer = #pinocchio::cpi::Signer::from(unsafe { core::slice::from_raw_parts(buffer.as_ptr() as *const #pinocchio::cpi::Seed, expr_len + 1) });
                     }
                 }
             }
@@ -278,6 +441,54 @@ impl AccountGenerator<'_> {
         })
     }
 
+    /// Generates the `<name>_signer` method of the context, calling a closure with the signer
+    /// seeds of the PDA. `fields` destructures the context into the names used by the seeds.
+    pub fn signer_method(
+        &self,
+        fields: &TokenStream,
+        has_bump_field: bool,
+    ) -> Result<Option<TokenStream>, syn::Error> {
+        let Some(pda_ctx) = self
+            .pda
+            .as_ref()
+            .filter(|pda| pda.keys.is_some() && pda.program_id.is_none())
+        else {
+            return Ok(None);
+        };
+
+        let name = &self.account.name;
+        let bump = if has_bump_field {
+            quote!(bumps.#name)
+        } else if let Some(ref bump) = pda_ctx.bump {
+            quote!(#bump)
+        } else {
+            return Ok(None);
+        };
+
+        let pda_bump = AccountIdents::new(name).bump;
+        let signer_init = self.get_signer_init(pda_ctx)?;
+        let method = format_ident!("{}_signer", name);
+        let doc = format!(" Calls `f` with the signer seeds of `{name}`.");
+
+        let pinocchio = paths::pinocchio();
+        let errors = paths::typhoon_errors();
+
+        Ok(Some(quote! {
+            #[doc = #doc]
+            #[inline(always)]
+            pub fn #method<R>(
+                &self,
+                f: impl FnOnce(#pinocchio::cpi::Signer) -> ::core::result::Result<R, #errors::Error>,
+            ) -> ::core::result::Result<R, #errors::Error> {
+                #[allow(unused_variables)]
+                let Self { #fields .. } = self;
+                let #pda_bump = #bump;
+                #signer_init
+                f(signer)
+            }
+        }))
+    }
+
     fn get_init_token(
         &self,
         ctx: &InitContext,
@@ -299,6 +510,7 @@ impl AccountGenerator<'_> {
                 is_ata,
                 mint,
                 owner,
+                ..
             } => {
                 let Some(owner) = owner else {
                     error!(name, "An `owner` needs to be specified for the `init` or `init_if_needed` constraint.");
@@ -307,16 +519,25 @@ impl AccountGenerator<'_> {
                     error!(name, "A `mint` needs to be specified for the `init` or `init_if_needed` constraint.");
                 };
 
+                let token_program = ctx.token_program()?;
+
                 if *is_ata {
-                    quote!(SplCreateToken::create_associated_token_account(#name, &#payer, &#mint, &#owner, &system_program, &token_program)?)
+                    let system_program = ctx.system_program()?;
+                    if ctx.is_init_if_needed {
+                        quote!(SplCreateToken::create_idempotent_associated_token_account(#name, &#payer, &#mint, &#owner, &#system_program, &#token_program)?)
+                    } else {
+                        quote!(SplCreateToken::create_associated_token_account(#name, &#payer, &#mint, &#owner, &#system_program, &#token_program)?)
+                    }
                 } else {
-                    quote!(SplCreateToken::create_token_account(#name, &rent, &#payer, &#mint, &#owner, #signers)?)
+                    quote!(SplCreateToken::create_token_account(#name, &rent, &#payer, &#mint, &#owner, &#token_program, #signers)?)
                 }
             }
             AccountType::Mint {
                 decimals,
                 authority,
                 freeze_authority,
+                extensions,
+                ..
             } => {
                 let default_decimals = parse_quote!(9);
                 let decimals = decimals.as_ref().unwrap_or(&default_decimals);
@@ -328,7 +549,13 @@ impl AccountGenerator<'_> {
                 } else {
                     quote!(None)
                 };
-                quote!(SplCreateMint::create_mint(#name, &rent, &#payer, &#authority, #decimals, #f_auth_token, #signers)?)
+                let token_program = ctx.token_program()?;
+                if extensions.is_empty() {
+                    quote!(SplCreateMint::create_mint(#name, &rent, &#payer, &#authority, #decimals, #f_auth_token, &#token_program, #signers)?)
+                } else {
+                    let extensions = mint_extensions(name, extensions)?;
+                    quote!(SplCreateMint::create_mint_with_extensions(#name, &rent, &#payer, &#authority, #decimals, #f_auth_token, &[#(#extensions),*], &#token_program, #signers)?)
+                }
             }
             AccountType::Other { space, .. } => {
                 let account_ty = &self.account.inner_ty;
@@ -406,28 +633,50 @@ impl AccountGenerator<'_> {
         let guard = gen_address_guard(
             quote!(#name.address()),
             quote!(&#pda_key),
-            quote!(Error::new(ProgramError::InvalidSeeds).with_account(#name_str)),
+            error_context(
+                quote!(Error::new(ProgramError::InvalidSeeds).with_account(#name_str)),
+                "seeds",
+                name.span(),
+            ),
         );
 
         Ok(quote! { #pda #guard })
     }
 
     fn verify_type_constraints(&self, idents: &AccountIdents) -> TokenStream {
-        let name_str = self.account.name.to_string();
+        let name = &self.account.name;
+        let name_str = name.to_string();
         let state = &idents.state;
+        let error =
+            |code: TokenStream| quote!(Error::from(ErrorCode::#code).with_account(#name_str));
 
         match self.account_ty {
             AccountType::TokenAccount {
+                is_ata,
                 ref mint,
                 ref owner,
-                ..
+                ref checks,
             } => {
+                let (mint_constraint, owner_constraint, token_program_constraint) = if is_ata {
+                    (
+                        "associated_token::mint",
+                        "associated_token::authority",
+                        "associated_token::token_program",
+                    )
+                } else {
+                    ("token::mint", "token::owner", "token::token_program")
+                };
+
                 let mut token = TokenStream::new();
                 if let Some(mint) = mint {
                     token.extend(gen_address_guard(
                         quote!(#state.mint()),
                         quote!(#mint.address()),
-                        quote!(ErrorCode::TokenConstraintViolated.into()),
+                        error_context(
+                            error(quote!(TokenMintConstraint)),
+                            mint_constraint,
+                            mint.span(),
+                        ),
                     ));
                 }
 
@@ -435,12 +684,139 @@ impl AccountGenerator<'_> {
                     token.extend(gen_address_guard(
                         quote!(#state.owner()),
                         quote!(#owner.address()),
-                        quote!(ErrorCode::TokenConstraintViolated.into()),
+                        error_context(
+                            error(quote!(TokenOwnerConstraint)),
+                            owner_constraint,
+                            owner.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(delegate) = &checks.delegate {
+                    token.extend(gen_guard(
+                        quote!(#state.delegate().is_some_and(|__address| address::address_eq(__address, #delegate.address()))),
+                        error_context(
+                            error(quote!(TokenDelegateConstraint)),
+                            "token::delegate",
+                            delegate.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(close_authority) = &checks.close_authority {
+                    token.extend(gen_guard(
+                        quote!(#state.close_authority().is_some_and(|__address| address::address_eq(__address, #close_authority.address()))),
+                        error_context(
+                            error(quote!(TokenCloseAuthorityConstraint)),
+                            "token::close_authority",
+                            close_authority.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(is_frozen) = &checks.is_frozen {
+                    token.extend(gen_guard(
+                        quote!(#state.is_frozen() == #is_frozen),
+                        error_context(
+                            error(quote!(TokenStateConstraint)),
+                            "token::is_frozen",
+                            is_frozen.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(ConstraintComparison { op, value }) = &checks.amount {
+                    token.extend(gen_guard(
+                        quote!(#state.amount() #op #value),
+                        error_context(
+                            error(quote!(TokenAmountConstraint)),
+                            "token::amount",
+                            value.span(),
+                        ),
                     ));
                 }
+
+                if let Some(token_program) = &checks.token_program {
+                    token.extend(gen_guard(
+                        quote!(#name.owned_by(#token_program.address())),
+                        error_context(
+                            error(quote!(TokenProgramConstraint)),
+                            token_program_constraint,
+                            token_program.span(),
+                        ),
+                    ));
+                }
+
+                // The address is derived with the token program owning the account, checked
+                // against `associated_token::token_program` above when specified.
+                if let (true, Some(mint), Some(owner)) = (is_ata, mint, owner) {
+                    token.extend(gen_address_guard(
+                        quote!(#name.address()),
+                        quote!(&find_associated_token_address(#mint.address(), #owner.address(), token_program_id(#name.as_ref()))),
+                        error_context(
+                            error(quote!(AssociatedTokenConstraint)),
+                            "associated_token",
+                            name.span(),
+                        ),
+                    ));
+                }
+
+                token
+            }
+            AccountType::Mint {
+                ref decimals,
+                ref authority,
+                ref freeze_authority,
+                ref supply,
+                ..
+            } => {
+                let mut token = TokenStream::new();
+                if let Some(authority) = authority {
+                    token.extend(gen_guard(
+                        quote!(#state.mint_authority().is_some_and(|__address| address::address_eq(__address, #authority))),
+                        error_context(
+                            error(quote!(MintAuthorityConstraint)),
+                            "mint::authority",
+                            authority.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(freeze_authority) = freeze_authority.as_ref() {
+                    token.extend(gen_guard(
+                        quote!(#state.freeze_authority().is_some_and(|__address| address::address_eq(__address, #freeze_authority))),
+                        error_context(
+                            error(quote!(MintFreezeAuthorityConstraint)),
+                            "mint::freeze_authority",
+                            freeze_authority.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(decimals) = decimals {
+                    token.extend(gen_guard(
+                        quote!(#state.decimals() == #decimals),
+                        error_context(
+                            error(quote!(MintDecimalsConstraint)),
+                            "mint::decimals",
+                            decimals.span(),
+                        ),
+                    ));
+                }
+
+                if let Some(ConstraintComparison { op, value }) = supply.as_ref() {
+                    token.extend(gen_guard(
+                        quote!(#state.supply() #op #value),
+                        error_context(
+                            error(quote!(MintSupplyConstraint)),
+                            "mint::supply",
+                            value.span(),
+                        ),
+                    ));
+                }
+
                 token
             }
-            AccountType::Mint { .. } => TokenStream::new(),
             AccountType::Other { ref targets, .. } => {
                 let basic_error: Expr = parse_quote!(ErrorCode::HasOneConstraint);
                 targets
@@ -450,7 +826,11 @@ impl AccountGenerator<'_> {
                         gen_address_guard(
                             quote!(&#state.#target),
                             quote!(#target.address()),
-                            quote!(Error::from(#error).with_account(#name_str)),
+                            error_context(
+                                quote!(Error::from(#error).with_account(#name_str)),
+                                "has_one",
+                                target.span(),
+                            ),
                         )
                     })
                     .collect()
@@ -459,14 +839,21 @@ impl AccountGenerator<'_> {
     }
 
     fn verify_assertions(&self) -> TokenStream {
+        let name_str = self.account.name.to_string();
+
         self.asserts
             .iter()
             .map(|ConstraintAssert { assert, error }| {
                 let basic_error: Expr = parse_quote!(ErrorCode::AssertConstraint);
                 let error = error.as_ref().unwrap_or(&basic_error);
+                let error = error_context(
+                    quote!(Error::from(#error).with_account(#name_str)),
+                    "assert",
+                    assert.span(),
+                );
                 quote! {
                     if hint::unlikely(!(#assert)) {
-                        return Err(#error.into());
+                        return Err(#error);
                     }
                 }
             })
@@ -495,6 +882,7 @@ impl AccountGenerator<'_> {
 
     pub fn generate(self) -> Result<TokenStream, syn::Error> {
         let name = &self.account.name;
+        let name_str = name.to_string();
         let idents = AccountIdents::new(name);
         let pda_bump = &idents.bump;
 
@@ -532,7 +920,11 @@ impl AccountGenerator<'_> {
             token.extend(gen_address_guard(
                 quote!(#name.address()),
                 quote!(#check),
-                quote!(#error.into()),
+                error_context(
+                    quote!(Error::from(#error).with_account(#name_str)),
+                    "address",
+                    check.span(),
+                ),
             ));
         }
 
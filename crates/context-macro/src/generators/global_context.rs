@@ -75,12 +75,46 @@ impl<'a> GlobalContext<'a> {
         Some((bumps_struct, bumps_var))
     }
 
+    /// Generates the `<name>_signer` methods of the PDAs of the context.
+    pub fn generate_signers(
+        &self,
+        context: &ParsingContext,
+    ) -> Result<Vec<TokenStream>, syn::Error> {
+        let mut fields: Vec<Ident> = context
+            .accounts
+            .iter()
+            .map(|account| account.name.clone())
+            .collect();
+        if !self.bumps.is_empty() {
+            fields.push(format_ident!("bumps"));
+        }
+        if context.args.is_some() {
+            fields.push(format_ident!("args"));
+        }
+        let fields = quote!(#(#fields,)*);
+
+        let mut signers = Vec::new();
+        for account in &self.accounts {
+            let has_bump_field = self.bumps.contains(&account.account.name.to_string());
+            signers.extend(account.signer_method(&fields, has_bump_field)?);
+        }
+
+        Ok(signers)
+    }
+
     pub fn from_parsing_context(context: &'a ParsingContext) -> Result<Self, syn::Error> {
         let mut need_rent = false;
         let mut accounts: Vec<AccountGenerator<'_>> = Vec::new();
         let mut bumps = HashSet::new();
         let mut program_checks = HashSet::new();
         let mut states = HashSet::new();
+        let program_field = |program: &str| {
+            context
+                .accounts
+                .iter()
+                .find(|account| account.inner_ty == program)
+                .map(|account| account.name.clone())
+        };
 
         //TODO optimize sorting etc..
         for account in &context.accounts {
@@ -89,11 +123,14 @@ impl<'a> GlobalContext<'a> {
                     is_ata: false,
                     mint: None,
                     owner: None,
+                    checks: Box::default(),
                 },
                 "Mint" => AccountType::Mint {
                     authority: None,
                     decimals: None,
                     freeze_authority: Box::new(None),
+                    supply: Box::new(None),
+                    extensions: Vec::new(),
                 },
                 _ => AccountType::Other {
                     space: None,
@@ -147,6 +184,13 @@ impl<'a> GlobalContext<'a> {
                             error!(name, "`seeds` or `seeded` are already defined.")
                         }
 
+                        if !matches!(generator.account_ty, AccountType::Other { .. }) {
+                            error!(
+                                name,
+                                "`seeded` cannot be used on `Mint` or `TokenAccount` type, use `seeds` instead."
+                            )
+                        }
+
                         generator.pda = Some(PdaContext {
                             keys: constraint_seeded.0.to_owned(),
                             bump: None,
@@ -217,13 +261,31 @@ impl<'a> GlobalContext<'a> {
                             is_ata,
                             mint,
                             owner,
+                            checks,
                         } = &mut generator.account_ty
                         {
-                            if *is_ata {
-                                error!(name, "`associated_token` is already defined.");
+                            let is_init = generator
+                                .init
+                                .as_ref()
+                                .is_some_and(|init| !init.is_init_if_needed);
+
+                            match constraint_token {
+                                ConstraintToken::Mint(_) | ConstraintToken::Owner(_) if *is_ata => {
+                                    error!(name, "`associated_token` is already defined.");
+                                }
+                                ConstraintToken::Mint(_) | ConstraintToken::Owner(_) => (),
+                                _ if is_init => {
+                                    error!(
+                                        name,
+                                        "This `token` constraint cannot be used with `init`."
+                                    );
+                                }
+                                _ => (),
                             }
 
-                            if generator.init.is_none() {
+                            if !is_init
+                                && !matches!(constraint_token, ConstraintToken::TokenProgram(_))
+                            {
                                 states.insert(name.to_string());
                             }
 
@@ -232,6 +294,21 @@ impl<'a> GlobalContext<'a> {
                                     *mint = Some(ident.to_owned());
                                 }
                                 ConstraintToken::Owner(expr) => *owner = Some(expr.to_owned()),
+                                ConstraintToken::Delegate(expr) => {
+                                    checks.delegate = Some(expr.to_owned())
+                                }
+                                ConstraintToken::CloseAuthority(expr) => {
+                                    checks.close_authority = Some(expr.to_owned())
+                                }
+                                ConstraintToken::IsFrozen(expr) => {
+                                    checks.is_frozen = Some(expr.to_owned())
+                                }
+                                ConstraintToken::Amount(comparison) => {
+                                    checks.amount = Some(comparison.to_owned())
+                                }
+                                ConstraintToken::TokenProgram(ident) => {
+                                    checks.token_program = Some(ident.to_owned())
+                                }
                             }
                         } else {
                             error!(
@@ -245,6 +322,8 @@ impl<'a> GlobalContext<'a> {
                             decimals,
                             authority,
                             freeze_authority,
+                            supply,
+                            extensions,
                         } = &mut generator.account_ty
                         {
                             states.insert(name.to_string());
@@ -257,6 +336,20 @@ impl<'a> GlobalContext<'a> {
                                 ConstraintMint::FreezeAuthority(expr) => {
                                     **freeze_authority = Some(expr.to_owned())
                                 }
+                                ConstraintMint::Supply(_)
+                                    if generator
+                                        .init
+                                        .as_ref()
+                                        .is_some_and(|init| !init.is_init_if_needed) =>
+                                {
+                                    error!(name, "`mint::supply` cannot be used with `init`.")
+                                }
+                                ConstraintMint::Supply(comparison) => {
+                                    **supply = Some(comparison.to_owned())
+                                }
+                                ConstraintMint::Extension(extension) => {
+                                    extensions.push(extension.to_owned())
+                                }
                             }
                         } else {
                             error!(
@@ -270,18 +363,23 @@ impl<'a> GlobalContext<'a> {
                             mint,
                             owner,
                             is_ata,
+                            checks,
                         } = &mut generator.account_ty
                         {
                             *is_ata = true;
-                            states.insert(name.to_string());
 
                             match constraint_associated_token {
                                 ConstraintAssociatedToken::Mint(ident) => {
+                                    states.insert(name.to_string());
                                     *mint = Some(ident.to_owned());
                                 }
                                 ConstraintAssociatedToken::Authority(ident) => {
+                                    states.insert(name.to_string());
                                     *owner = Some(parse_quote!(#ident));
                                 }
+                                ConstraintAssociatedToken::TokenProgram(ident) => {
+                                    checks.token_program = Some(ident.to_owned())
+                                }
                             }
                         } else {
                             error!(
@@ -300,7 +398,7 @@ impl<'a> GlobalContext<'a> {
 
                         generator.init = Some(InitContext {
                             is_init_if_needed: true,
-                            payer: None,
+                            ..Default::default()
                         })
                     }
                     Constraint::Assert(constraint_assert) => {
@@ -318,6 +416,23 @@ impl<'a> GlobalContext<'a> {
                 }
             }
 
+            if let Some(init) = &mut generator.init {
+                init.system_program = program_field("System");
+                init.token_program = match &generator.account_ty {
+                    AccountType::TokenAccount { checks, .. } if checks.token_program.is_some() => {
+                        checks.token_program.clone()
+                    }
+                    _ => program_field("TokenProgram"),
+                };
+            }
+
+            if account.is_lazy && generator.init.is_some() {
+                error!(
+                    name,
+                    "`init` and `init_if_needed` cannot be used on a `Lazy` account."
+                );
+            }
+
             for program in generator.needs_programs() {
                 program_checks.insert(program);
             }
@@ -327,6 +442,12 @@ impl<'a> GlobalContext<'a> {
         for state in states.iter() {
             for account in &mut accounts {
                 if &account.account.name.to_string() == state {
+                    if account.account.is_lazy {
+                        error!(
+                            &account.account.name,
+                            "Constraints reading the account data cannot be used on a `Lazy` account."
+                        );
+                    }
                     account.init_state = true
                 }
             }
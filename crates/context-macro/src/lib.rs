@@ -14,6 +14,7 @@ use {
 mod context;
 mod generators;
 mod injector;
+mod paths;
 mod remover;
 mod sorter;
 mod visitor;
@@ -35,6 +36,7 @@ struct TokenGenerator {
     item_struct: ItemStruct,
     accounts_token: Vec<TokenStream2>,
     bumps: Option<BumpsStruct>,
+    signers: Vec<TokenStream2>,
     args: Option<(Ident, Option<TokenStream2>)>,
     needs_rent: bool,
 }
@@ -56,6 +58,7 @@ impl TokenGenerator {
 
         let bumps = global_context.generate_bumps(&context);
         let args = global_context.generate_args(&context);
+        let signers = global_context.generate_signers(&context)?;
 
         let accounts_token = global_context
             .accounts
@@ -68,6 +71,7 @@ impl TokenGenerator {
             item_struct: context.item_struct,
             accounts_token,
             bumps,
+            signers,
             args,
         })
     }
@@ -78,7 +82,7 @@ impl ToTokens for TokenGenerator {
         let name = &self.item_struct.ident;
         let generics = &self.item_struct.generics;
 
-        let (_, ty_generics, _) = generics.split_for_impl();
+        let (struct_impl_generics, ty_generics, struct_where_clause) = generics.split_for_impl();
 
         // patch the lifetime of the new context here
         let generics = &mut generics.to_owned();
@@ -113,7 +117,7 @@ impl ToTokens for TokenGenerator {
 
         let args_ident = format_ident!("args");
         let (args_assign, args_struct) = self.args.as_ref().map(|(name, args_struct)| {
-            let args_field: Field = parse_quote!(pub #args_ident: &'info #name);
+            let args_field: Field = parse_quote!(pub #args_ident: ContextArgs<'info, #name>);
             struct_fields.push(&args_ident);
             FieldInjector::new(args_field).visit_item_struct_mut(account_struct);
 
@@ -153,12 +157,22 @@ impl ToTokens for TokenGenerator {
             impl #impl_generics Context for #name #ty_generics #where_clause {}
         };
 
+        let signers = &self.signers;
+        let impl_signers = (!signers.is_empty()).then(|| {
+            quote! {
+                impl #struct_impl_generics #name #ty_generics #struct_where_clause {
+                    #(#signers)*
+                }
+            }
+        });
+
         let doc = prettyplease::unparse(
             &syn::parse2::<syn::File>(quote! {
                 #bumps_struct
                 #args_struct
 
                 #impl_context
+                #impl_signers
             })
             .unwrap(),
         );
@@ -179,7 +193,7 @@ impl ToTokens for TokenGenerator {
             #account_struct
 
             #impl_context
-
+            #impl_signers
         };
         expanded.to_tokens(tokens);
     }
@@ -0,0 +1,35 @@
+use {
+    proc_macro2::TokenStream,
+    proc_macro_crate::{crate_name, FoundCrate},
+    quote::{format_ident, quote},
+};
+
+/// Path to the dependency `name` of the program, or to `typhoon_path` in the `typhoon` crate if
+/// the program only depends on it.
+fn dependency_path(name: &str, typhoon_path: TokenStream) -> TokenStream {
+    let path = |name: &str| {
+        let ident = format_ident!("{}", name.replace('-', "_"));
+        quote!(::#ident)
+    };
+    match crate_name(name) {
+        Ok(FoundCrate::Name(name)) => path(&name),
+        Ok(FoundCrate::Itself) => path(name),
+        Err(_) => match crate_name("typhoon") {
+            Ok(FoundCrate::Name(typhoon)) => {
+                let typhoon = path(&typhoon);
+                quote!(#typhoon::#typhoon_path)
+            }
+            _ => path(name),
+        },
+    }
+}
+
+/// Path to `pinocchio`.
+pub fn pinocchio() -> TokenStream {
+    dependency_path("pinocchio", quote!(prelude::pinocchio))
+}
+
+/// Path to `typhoon_errors`.
+pub fn typhoon_errors() -> TokenStream {
+    dependency_path("typhoon-errors", quote!(lib))
+}
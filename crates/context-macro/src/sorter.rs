@@ -61,6 +61,7 @@ impl ContextVisitor for DependencyLinker {
         match constraint {
             ConstraintAssociatedToken::Mint(ident) => self.add_dependency(ident),
             ConstraintAssociatedToken::Authority(ident) => self.add_dependency(ident),
+            ConstraintAssociatedToken::TokenProgram(ident) => self.add_dependency(ident),
         }
         Ok(())
     }
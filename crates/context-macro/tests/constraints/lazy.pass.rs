@@ -0,0 +1,47 @@
+use pinocchio::{
+    address::{self, address_eq, declare_id, Address},
+    error::ProgramError,
+    hint,
+    AccountView,
+};
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Counter {
+    pub count: u64,
+}
+
+#[context]
+pub struct Increment {
+    pub authority: Signer,
+    #[constraint(seeds = [b"counter".as_ref()], bump = 255)]
+    pub counter: Mut<Lazy<Account<Counter>>>,
+    pub other: Lazy<Account<Counter>>,
+}
+
+pub fn increment(ctx: Increment) -> ProgramResult {
+    if ctx.authority.address() != ctx.other.address() {
+        let counter = ctx.counter.load_mut()?;
+        counter.mut_data()?.count += 1;
+    }
+
+    let _ = ctx.other.load()?.data()?.count;
+
+    Ok(())
+}
+
+pub fn main() {}
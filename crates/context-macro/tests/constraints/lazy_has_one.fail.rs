@@ -0,0 +1,28 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{address_eq, declare_id},
+        Address,
+    },
+    typhoon_account_macro::*,
+    typhoon_context_macro::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Counter {
+    pub authority: Address,
+}
+
+#[context]
+pub struct Increment {
+    pub authority: Signer,
+    #[constraint(has_one = authority)]
+    pub counter: Lazy<Account<Counter>>,
+}
+
+pub fn main() {}
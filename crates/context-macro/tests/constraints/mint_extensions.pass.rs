@@ -0,0 +1,72 @@
+use pinocchio::{
+    address::{address_eq, declare_id, Address},
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView,
+};
+use {
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_token::*,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+#[args(maximum_fee: u64)]
+pub struct InitMint {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = payer.address(),
+        mint::extensions::transfer_fee::config_authority = payer.address(),
+        mint::extensions::transfer_fee::basis_points = 50,
+        mint::extensions::transfer_fee::maximum_fee = args.maximum_fee,
+        mint::extensions::non_transferable,
+        mint::extensions::permanent_delegate::delegate = payer.address(),
+        mint::extensions::metadata_pointer::metadata_address = mint.address(),
+    )]
+    pub mint: Mut<Signer<Account<Mint>>>,
+    pub token_program: Program<TokenProgram>,
+    pub system_program: Program<System>,
+}
+
+pub fn init_mint(ctx: InitMint) -> ProgramResult {
+    let mint = ctx.mint;
+    let fee = mint.extension::<TransferFeeConfig>()?;
+    let _ = fee.map(|fee| fee.newer_transfer_fee().maximum_fee());
+
+    Ok(())
+}
+
+#[context]
+pub struct InitMetadata {
+    pub payer: Mut<Signer>,
+    pub mint: Mut<Account<Mint>>,
+}
+
+pub fn init_metadata(ctx: InitMetadata) -> ProgramResult {
+    let (name, symbol, uri) = ("Typhoon", "TYP", "https://typhoon.rs");
+    let space = TokenMetadata::space(name, symbol, uri) + TokenMetadata::field_space("edition", "1");
+    let _lamports = rent_for_extra_space(&ctx.mint, space, &Rent::get()?)?;
+
+    ctx.mint
+        .initialize_metadata(&ctx.payer, &ctx.payer, name, symbol, uri, None)?;
+    ctx.mint
+        .update_metadata_field(&ctx.payer, MetadataField::Key("edition"), "1", None)?;
+
+    let metadata = ctx.mint.token_metadata()?;
+    let _ = metadata.map(|metadata| metadata.get("edition").is_some());
+
+    Ok(())
+}
+
+pub fn main() {}
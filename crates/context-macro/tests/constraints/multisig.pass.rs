@@ -0,0 +1,76 @@
+use pinocchio::{
+    address::{self, address_eq, declare_id, Address},
+    error::ProgramError,
+    hint,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView,
+};
+use {
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_token::*,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct CreateMultisig {
+    pub payer: Mut<Signer>,
+    pub multisig: Mut<Signer<UncheckedAccount>>,
+    pub first: UncheckedAccount,
+    pub second: UncheckedAccount,
+    pub third: UncheckedAccount,
+    pub token_program: Program<TokenProgram>,
+    pub system_program: Program<System>,
+}
+
+pub fn create_multisig(ctx: CreateMultisig) -> ProgramResult {
+    let _multisig: Mut<Account<TokenMultisig>> = ctx.multisig.as_ref().create_multisig(
+        &Rent::get()?,
+        &ctx.payer,
+        &[ctx.first.as_ref(), ctx.second.as_ref(), ctx.third.as_ref()],
+        2,
+        &ctx.token_program,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[context]
+#[args(amount: u64)]
+pub struct MultisigMintTo {
+    pub multisig: Account<TokenMultisig>,
+    pub first: Signer,
+    pub second: Signer,
+    #[constraint(mint::authority = multisig.address())]
+    pub mint: Mut<Account<Mint>>,
+    #[constraint(token::mint = mint)]
+    pub vault: Mut<Account<TokenAccount>>,
+    pub token_program: Program<TokenProgram>,
+}
+
+pub fn mint_to(ctx: MultisigMintTo) -> ProgramResult {
+    let multisig = ctx.multisig.data()?;
+    if multisig.required_signers() > 2 {
+        return Err(ProgramError::MissingRequiredSignature.into());
+    }
+
+    let signers = [ctx.first.as_ref(), ctx.second.as_ref()];
+    let authority = MultisigAuthority::new(&ctx.multisig, &signers);
+    ctx.mint.mint_to(&ctx.vault, &authority, ctx.args.amount, None)?;
+    ctx.mint.set_authority(
+        &authority,
+        spl_instructions::AuthorityType::FreezeAccount,
+        None,
+        None,
+    )
+}
+
+pub fn main() {}
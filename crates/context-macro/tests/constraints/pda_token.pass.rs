@@ -0,0 +1,67 @@
+use pinocchio::{
+    address::{self, address_eq, declare_id, Address},
+    cpi::Signer as CpiSigner,
+    error::ProgramError,
+    hint,
+    instruction::seeds,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView,
+};
+use {
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_token::*,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+#[args(amount: u64)]
+pub struct Deposit {
+    pub payer: Mut<Signer>,
+    pub mint: Account<Mint>,
+    #[constraint(
+        seeds = [b"authority".as_ref(), mint.address().as_ref()],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount,
+    #[constraint(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::owner = vault_authority.address(),
+        seeds = [b"vault".as_ref(), mint.address().as_ref()],
+        bump
+    )]
+    pub vault: Mut<Account<TokenAccount>>,
+    #[constraint(token::mint = mint)]
+    pub destination: Mut<Account<TokenAccount>>,
+    pub token_program: Program<TokenProgram>,
+    pub system_program: Program<System>,
+}
+
+pub fn deposit(ctx: Deposit) -> ProgramResult {
+    let decimals = ctx.mint.data()?.decimals();
+    ctx.vault_authority_signer(|signer| {
+        ctx.vault.transfer_checked(
+            &ctx.mint,
+            &ctx.destination,
+            &ctx.vault_authority,
+            ctx.args.amount,
+            decimals,
+            Some(&[signer]),
+        )
+    })?;
+    ctx.vault_authority_signer(|signer| {
+        ctx.vault
+            .close(&ctx.payer, &ctx.vault_authority, Some(&[signer]))
+    })
+}
+
+pub fn main() {}
@@ -0,0 +1,96 @@
+use pinocchio::{
+    address::{self, address_eq, declare_id, Address},
+    error::ProgramError,
+    hint,
+    AccountView,
+};
+use {
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_token::*,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct CheckTokens {
+    pub payer: Signer,
+    pub delegate: UncheckedAccount,
+    #[constraint(
+        mint::authority = payer.address(),
+        mint::freeze_authority = payer.address(),
+        mint::decimals = 6,
+        mint::supply > 0
+    )]
+    pub mint: Account<Mint>,
+    #[constraint(
+        token::mint = mint,
+        token::owner = payer,
+        token::delegate = delegate,
+        token::close_authority = payer,
+        token::is_frozen = false,
+        token::amount >= 1_000,
+        token::token_program = spl_token
+    )]
+    pub vault: Account<TokenAccount>,
+    pub spl_token: Program<TokenProgram>,
+}
+
+pub fn check_tokens(_ctx: CheckTokens) -> ProgramResult {
+    Ok(())
+}
+
+#[context]
+pub struct CheckAta {
+    pub owner: Signer,
+    pub mint: Account<Mint>,
+    #[constraint(
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub owner_ata: Account<TokenAccount>,
+    pub token_program: Program<TokenProgram>,
+}
+
+pub fn check_ata(_ctx: CheckAta) -> ProgramResult {
+    Ok(())
+}
+
+#[context]
+#[args(amount: u64)]
+pub struct WrapSol {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        token::mint = NativeMint,
+        token::owner = payer
+    )]
+    pub wsol: Mut<Account<TokenAccount>>,
+    pub token_program: Program<TokenProgram>,
+    pub system_program: Program<System>,
+}
+
+#[context]
+pub struct CheckWsol2022 {
+    #[constraint(
+        token::mint = NativeMint2022
+    )]
+    pub wsol: Account<TokenAccount>,
+}
+
+pub fn check_wsol_2022(_ctx: CheckWsol2022) -> ProgramResult {
+    Ok(())
+}
+
+pub fn wrap(ctx: WrapSol) -> ProgramResult {
+    wrap_sol(&ctx.payer, &ctx.wsol, ctx.args.amount)?;
+    unwrap_sol(&ctx.wsol, &ctx.payer, &ctx.payer, None)
+}
+
+pub fn main() {}
@@ -0,0 +1,41 @@
+use {
+    pinocchio::address::declare_id, typhoon_context_macro::*,
+    typhoon_program_id_macro::program_id,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct InitToken {
+    pub payer: Mut<Signer>,
+    pub mint: Account<Mint>,
+    #[constraint(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::owner = payer.address(),
+        token::amount >= 1_000,
+        seeds = [b"vault".as_ref()],
+        bump
+    )]
+    pub vault: Mut<Account<TokenAccount>>,
+    pub spl_token: Program<TokenProgram>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct InitMint {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = payer.address(),
+        mint::supply > 0
+    )]
+    pub mint: Mut<Signer<Account<Mint>>>,
+    pub spl_token: Program<TokenProgram>,
+    pub system: Program<System>,
+}
+
+pub fn main() {}
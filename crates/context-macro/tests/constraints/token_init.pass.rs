@@ -0,0 +1,83 @@
+use pinocchio::{
+    address::{self, address_eq, declare_id, Address},
+    cpi::Signer as CpiSigner,
+    error::ProgramError,
+    hint,
+    instruction::seeds,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView,
+};
+use {
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_token::*,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct InitTokens {
+    pub payer: Mut<Signer>,
+    pub owner: UncheckedAccount,
+    #[constraint(
+        init,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = payer.address(),
+    )]
+    pub mint: Mut<Signer<Account<Mint>>>,
+    #[constraint(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::owner = owner.address(),
+        seeds = [b"vault".as_ref()],
+        bump
+    )]
+    pub vault: Mut<Account<TokenAccount>>,
+    #[constraint(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = owner
+    )]
+    pub owner_ata: Mut<Account<TokenAccount>>,
+    pub spl_token: Program<TokenProgram>,
+    pub ata: Program<AtaTokenProgram>,
+    pub system: Program<System>,
+}
+
+pub fn init_tokens(_ctx: InitTokens) -> ProgramResult {
+    Ok(())
+}
+
+#[context]
+pub struct InitAta2022 {
+    pub payer: Mut<Signer>,
+    pub owner: UncheckedAccount,
+    pub mint: Account<Mint>,
+    #[constraint(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_2022
+    )]
+    pub owner_ata: Mut<Account<TokenAccount>>,
+    pub spl_token: Program<TokenProgram>,
+    pub token_2022: Program<TokenProgram>,
+    pub ata: Program<AtaTokenProgram>,
+    pub system: Program<System>,
+}
+
+pub fn init_ata_2022(_ctx: InitAta2022) -> ProgramResult {
+    Ok(())
+}
+
+pub fn main() {}
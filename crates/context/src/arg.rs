@@ -6,7 +6,10 @@ use {
     typhoon_traits::{Accessor, BytemuckStrategy},
 };
 
-pub type ArgData<'a, T, S> = <S as Accessor<'a, T>>::Data;
+pub type ArgData<'a, T, S> = <S as Accessor<'a, T>>::Read;
+
+/// Arguments declared with `#[args]` on a context, read with the default strategy of [`Arg`].
+pub type ContextArgs<'a, T> = ArgData<'a, T, BytemuckStrategy>;
 
 pub struct Arg<'a, T, S = BytemuckStrategy>(pub ArgData<'a, T, S>)
 where
@@ -119,7 +119,8 @@ macro_rules! basic_router {
                 .ok_or(ProgramError::InvalidInstructionData)?;
 
             let result = match discriminator {
-                $($dis => handle(program_id, accounts, data, $fn_ident),)*
+                $($dis => handle(program_id, accounts, data, $fn_ident)
+                    .map_err(|err| err.with_instruction(stringify!($fn_ident))),)*
                 _ => Err(ErrorCode::UnknownInstruction.into()),
             };
 
@@ -1,19 +1,17 @@
 use {
     proc_macro::TokenStream,
-    quote::{quote, ToTokens},
-    syn::parse_macro_input,
-    typhoon_syn::Errors,
+    proc_macro2::TokenStream as TokenStream2,
+    proc_macro_crate::{crate_name, FoundCrate},
+    quote::{format_ident, quote, quote_spanned, ToTokens},
+    syn::{parse_macro_input, spanned::Spanned, Fields},
+    typhoon_syn::{ErrorVariant, Errors, MsgSegment},
 };
 
 /// Derive macro for generating error implementations
 ///
 /// Usage:
 /// ```rust
-/// # use {
-/// #    pinocchio::error::{ProgramError, ToStr},
-/// #    typhoon_errors::Error,
-/// #    typhoon_errors_macro::TyphoonError
-/// # };
+/// # use typhoon_errors_macro::TyphoonError;
 /// #[derive(TyphoonError)]
 /// pub enum MyError {
 ///     #[msg("Error: Invalid owner")]
@@ -21,12 +19,37 @@ use {
 ///     #[msg("Error: Insufficient funds")]
 ///     InsufficientFunds,
 /// }
+///
+/// // Codes start at 6000.
+/// #[derive(TyphoonError)]
+/// #[error_offset = 6000]
+/// pub enum VaultError {
+///     #[msg("Error: Vault is locked")]
+///     Locked,
+///     // Logged as "Error: Need 10, have 5" with the `logging` feature.
+///     #[msg("Error: Need {needed}, have {available}")]
+///     InsufficientFunds { needed: u64, available: u64 },
+/// }
 /// ```
-#[proc_macro_derive(TyphoonError, attributes(msg))]
+///
+/// The codes of the built-in `ErrorCode` can't be reused.
+///
+/// # `#[msg]`
+///
+/// The message can interpolate the fields of the variant with `{field}`, or `{0}` for tuple
+/// variants, `{{` and `}}` being escaped braces:
+/// - The fields are only kept in the message logged by `log_error`, `ToStr` returns the raw
+///   message.
+/// - The interpolated fields must fit in 32 bytes with an alignment of at most 8, with or
+///   without the `logging` feature.
+/// - Every field type must implement `Default`, `TryFrom<u32>` builds the variant from its code
+///   alone to look up its message.
+#[proc_macro_derive(TyphoonError, attributes(msg, error_offset))]
 pub fn typhoon_error(input: TokenStream) -> TokenStream {
     let errors_token = parse_macro_input!(input as Errors);
 
     let name = &errors_token.name;
+    let errors = errors_crate();
 
     let (to_str_arms, try_from_arms) = errors_token
         .variants
@@ -35,26 +58,75 @@ pub fn typhoon_error(input: TokenStream) -> TokenStream {
             let variant_name = &v.name;
             let msg = &v.msg;
             let discriminant = &v.discriminant;
+            let defaults = default_fields(&v.fields);
             (
-                quote!(#name::#variant_name => #msg,),
-                quote!(#discriminant => Ok(#name::#variant_name),),
+                quote!(#name::#variant_name { .. } => #msg,),
+                quote!(#discriminant => ::core::result::Result::Ok(#name::#variant_name #defaults),),
             )
         })
         .collect::<(Vec<_>, Vec<_>)>();
+    let (codes, code_arms) = errors_token
+        .variants
+        .iter()
+        .map(|v| {
+            let variant_name = &v.name;
+            let discriminant = &v.discriminant;
+            (
+                quote!(#discriminant),
+                quote!(#name::#variant_name { .. } => #discriminant,),
+            )
+        })
+        .collect::<(Vec<_>, Vec<_>)>();
+    let reserved_checks = errors_token.variants.iter().map(|v| {
+        let discriminant = &v.discriminant;
+        let msg = format!(
+            "The error code {discriminant} of `{}` is reserved by `ErrorCode`.",
+            v.name
+        );
+        quote_spanned! {v.name.span()=>
+            const _: () = assert!(
+                !#errors::contains_code(
+                    <#errors::ErrorCode as #errors::ErrorCodes>::CODES,
+                    #discriminant,
+                ),
+                #msg,
+            );
+        }
+    });
+    let size_checks = errors_token
+        .variants
+        .iter()
+        .filter(|v| v.is_formatted())
+        .map(|v| {
+            let types = used_fields(v).into_iter().map(|(.., ty)| ty);
+            let msg = format!(
+                "The fields in the message of `{}` must fit in 32 bytes.",
+                v.name
+            );
+            quote_spanned! {v.name.span()=>
+                const _: () = assert!(#errors::fits_error_message::<(#(#types,)*)>(), #msg);
+            }
+        });
+    let with_message = with_message(&errors, name, &errors_token.variants);
 
     quote! {
-        impl TryFrom<u32> for #name {
-            type Error = ProgramError;
+        #(#reserved_checks)*
+        #(#size_checks)*
+
+        impl ::core::convert::TryFrom<u32> for #name {
+            type Error = #errors::__private::ProgramError;
 
-            fn try_from(value: u32) -> Result<Self, Self::Error> {
+            fn try_from(value: u32) -> ::core::result::Result<Self, Self::Error> {
                 match value {
                     #(#try_from_arms)*
-                    _ => Err(ProgramError::InvalidArgument),
+                    _ => ::core::result::Result::Err(
+                        #errors::__private::ProgramError::InvalidArgument,
+                    ),
                 }
             }
         }
 
-        impl ToStr for #name {
+        impl #errors::__private::ToStr for #name {
             fn to_str(&self) -> &'static str
             {
                 match self {
@@ -63,12 +135,138 @@ pub fn typhoon_error(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl From<#name> for Error {
+        impl #errors::ErrorCodes for #name {
+            const CODES: &'static [u32] = &[#(#codes),*];
+        }
+
+        impl ::core::convert::From<#name> for #errors::Error {
             fn from(value: #name) -> Self {
-                Error::new(ProgramError::Custom(value as u32))
+                let code = match value {
+                    #(#code_arms)*
+                };
+                let error = #errors::Error::new(#errors::__private::ProgramError::Custom(code));
+                #with_message
+                error
             }
         }
     }
     .into_token_stream()
     .into()
 }
+
+/// Path to `typhoon_errors`, through the `typhoon` re-exports if the crate only depends on it.
+fn errors_crate() -> TokenStream2 {
+    let path = |name: &str| {
+        let ident = format_ident!("{name}");
+        quote!(::#ident)
+    };
+    match crate_name("typhoon-errors") {
+        Ok(FoundCrate::Name(name)) => path(&name),
+        Ok(FoundCrate::Itself) => quote!(::typhoon_errors),
+        Err(_) => match crate_name("typhoon") {
+            Ok(FoundCrate::Name(name)) => {
+                let typhoon = path(&name);
+                quote!(#typhoon::lib)
+            }
+            _ => quote!(::typhoon_errors),
+        },
+    }
+}
+
+/// Fields of a variant built from a code, which carries no payload.
+fn default_fields(fields: &Fields) -> TokenStream2 {
+    // Spanned on the field type so a missing `Default` points at the field.
+    let default = |field: &syn::Field| {
+        let ty = &field.ty;
+        quote_spanned!(ty.span()=> <#ty as ::core::default::Default>::default())
+    };
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|f| &f.ident);
+            let defaults = fields.named.iter().map(default);
+            quote!({ #(#names: #defaults),* })
+        }
+        Fields::Unnamed(fields) => {
+            let defaults = fields.unnamed.iter().map(default);
+            quote!((#(#defaults),*))
+        }
+        Fields::Unit => quote!(),
+    }
+}
+
+/// Index, binding and type of the fields interpolated in the message of `variant`.
+fn used_fields(variant: &ErrorVariant) -> Vec<(usize, syn::Ident, &syn::Type)> {
+    variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| variant.segments.contains(&MsgSegment::Field(*i)))
+        .map(|(i, field)| (i, format_ident!("field_{i}"), &field.ty))
+        .collect()
+}
+
+/// Keeps the fields interpolated in the messages with a function formatting them when the
+/// error is logged, the code alone can't carry them.
+fn with_message(
+    errors: &TokenStream2,
+    name: &syn::Ident,
+    variants: &[ErrorVariant],
+) -> Option<TokenStream2> {
+    let formatted: Vec<_> = variants.iter().filter(|v| v.is_formatted()).collect();
+    if formatted.is_empty() {
+        return None;
+    }
+
+    let arms = formatted.iter().map(|v| {
+        let variant_name = &v.name;
+        let used = used_fields(v);
+        let pattern = match &v.fields {
+            Fields::Named(fields) => {
+                let bindings = used.iter().map(|(i, binding, _)| {
+                    let ident = &fields.named[*i].ident;
+                    quote!(#ident: #binding)
+                });
+                quote!({ #(#bindings,)* .. })
+            }
+            Fields::Unnamed(fields) => {
+                let bindings = (0..fields.unnamed.len()).map(|i| {
+                    match used.iter().find(|(used, ..)| *used == i) {
+                        Some((_, binding, _)) => binding.into_token_stream(),
+                        None => quote!(_),
+                    }
+                });
+                quote!((#(#bindings),*))
+            }
+            Fields::Unit => quote!(),
+        };
+        let bindings = used.iter().map(|(_, binding, _)| binding);
+        let types = used.iter().map(|(.., ty)| ty);
+        let appends = v.segments.iter().map(|segment| match segment {
+            MsgSegment::Lit(lit) => quote!(#errors::LogValue::append_to(#lit, logger);),
+            MsgSegment::Field(i) => {
+                let binding = format_ident!("field_{i}");
+                quote!(#errors::LogValue::append_to(#binding, logger);)
+            }
+        });
+        let fields = quote!((#(#bindings,)*));
+
+        quote! {
+            #name::#variant_name #pattern => error.with_message(
+                #fields,
+                |#fields: &(#(#types,)*), logger: &mut #errors::ErrorLogger| {
+                    #(#appends)*
+                },
+            ),
+        }
+    });
+    let fallback = (formatted.len() < variants.len()).then(|| quote!(_ => error,));
+
+    Some(quote! {
+        #errors::__with_logging! {
+            let error = match value {
+                #(#arms)*
+                #fallback
+            };
+        }
+    })
+}
@@ -0,0 +1,27 @@
+use {
+    pinocchio::error::{ProgramError, ToStr},
+    typhoon_errors::Error,
+    typhoon_errors_macro::TyphoonError,
+};
+
+#[derive(TyphoonError)]
+#[error_offset = 6000]
+pub enum VaultError {
+    #[msg("Error: Vault is locked")]
+    Locked,
+    #[msg("Error: Need {needed}, have {available}")]
+    InsufficientFunds { needed: u64, available: u64 },
+}
+
+fn main() {
+    let error: Error = VaultError::InsufficientFunds {
+        needed: 10,
+        available: 5,
+    }
+    .into();
+    assert_eq!(ProgramError::from(error), ProgramError::Custom(6001));
+    assert_eq!(
+        VaultError::try_from(6001).unwrap().to_str(),
+        "Error: Need {needed}, have {available}"
+    );
+}
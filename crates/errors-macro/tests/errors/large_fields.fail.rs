@@ -0,0 +1,9 @@
+use typhoon_errors_macro::TyphoonError;
+
+#[derive(TyphoonError)]
+pub enum VaultError {
+    #[msg("Error: {0} and {1} are not the vault")]
+    WrongVault([u8; 32], [u8; 32]),
+}
+
+fn main() {}
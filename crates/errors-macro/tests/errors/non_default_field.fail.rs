@@ -0,0 +1,11 @@
+use typhoon_errors_macro::TyphoonError;
+
+pub struct Amount(u64);
+
+#[derive(TyphoonError)]
+pub enum VaultError {
+    #[msg("Error: Not enough funds")]
+    InsufficientFunds { needed: Amount },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use typhoon_errors_macro::TyphoonError;
+
+#[derive(TyphoonError)]
+#[error_offset = 100]
+pub enum VaultError {
+    #[msg("Error: Vault is locked")]
+    Locked,
+}
+
+fn main() {}
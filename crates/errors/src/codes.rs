@@ -0,0 +1,147 @@
+/// Trait for error enums exposing the codes they use.
+pub trait ErrorCodes {
+    /// All the codes of the enum.
+    const CODES: &'static [u32];
+}
+
+/// Returns `true` if `code` is one of `codes`.
+pub const fn contains_code(codes: &[u32], code: u32) -> bool {
+    let mut i = 0;
+    while i < codes.len() {
+        if codes[i] == code {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Returns `true` if a code appears in more than one of the `sets`.
+pub const fn has_duplicate_codes(sets: &[&[u32]]) -> bool {
+    let mut i = 0;
+    while i < sets.len() {
+        let mut j = i + 1;
+        while j < sets.len() {
+            let mut k = 0;
+            while k < sets[i].len() {
+                if contains_code(sets[j], sets[i][k]) {
+                    return true;
+                }
+                k += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Combines several error enums into a single type used to look up their messages, e.g. with
+/// `log_error`. Compilation fails if two of the enums share a code.
+///
+/// ```
+/// # use typhoon_errors::{combine_errors, Error, ErrorCode};
+/// mod vault {
+///     # use typhoon_errors_macro::TyphoonError;
+///     #[derive(TyphoonError)]
+///     #[error_offset = 6000]
+///     pub enum VaultError {
+///         #[msg("Error: Vault is locked")]
+///         Locked,
+///     }
+/// }
+///
+/// combine_errors!(pub LogError = ErrorCode, vault::VaultError);
+///
+/// let error: Error = vault::VaultError::Locked.into();
+/// assert_eq!(error.to_str::<LogError>(), "Error: Vault is locked");
+/// ```
+#[macro_export]
+macro_rules! combine_errors {
+    ($vis:vis $name:ident = $($error:path),+ $(,)?) => {
+        /// Message of an error from one of the combined enums.
+        $vis struct $name(&'static str);
+
+        const _: () = assert!(
+            !$crate::has_duplicate_codes(&[$(<$error as $crate::ErrorCodes>::CODES),+]),
+            concat!("The errors combined in `", stringify!($name), "` share a code."),
+        );
+
+        impl ::core::convert::TryFrom<u32> for $name {
+            type Error = $crate::__private::ProgramError;
+
+            fn try_from(value: u32) -> ::core::result::Result<Self, Self::Error> {
+                $(
+                    if let ::core::result::Result::Ok(error) =
+                        <$error as ::core::convert::TryFrom<u32>>::try_from(value)
+                    {
+                        return ::core::result::Result::Ok($name(
+                            $crate::__private::ToStr::to_str(&error),
+                        ));
+                    }
+                )+
+                ::core::result::Result::Err($crate::__private::ProgramError::InvalidArgument)
+            }
+        }
+
+        impl $crate::__private::ToStr for $name {
+            fn to_str(&self) -> &'static str {
+                self.0
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Error, ErrorCode},
+        pinocchio::error::{ProgramError, ToStr},
+        typhoon_errors_macro::TyphoonError,
+    };
+
+    #[derive(TyphoonError)]
+    #[error_offset = 6000]
+    enum VaultError {
+        #[msg("Error: Vault is locked")]
+        Locked,
+        #[msg("Error: Vault is empty")]
+        Empty,
+    }
+
+    combine_errors!(CombinedError = crate::ErrorCode, self::VaultError);
+
+    #[test]
+    fn test_has_duplicate_codes() {
+        assert!(!has_duplicate_codes(&[&[0, 1], &[2, 3], &[6000]]));
+        assert!(has_duplicate_codes(&[&[0, 1], &[2, 3], &[1]]));
+        assert!(!has_duplicate_codes(&[&[0, 0]]));
+    }
+
+    #[test]
+    fn test_combine_errors() {
+        assert_eq!(
+            CombinedError::try_from(101).map(|error| error.to_str()),
+            Ok(ErrorCode::AccountNotSigner.to_str())
+        );
+        assert_eq!(
+            CombinedError::try_from(6001).map(|error| error.to_str()),
+            Ok("Error: Vault is empty")
+        );
+        assert_eq!(
+            CombinedError::try_from(6002).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+
+        let error: Error = VaultError::Locked.into();
+        assert_eq!(error.to_str::<CombinedError>(), "Error: Vault is locked");
+        let error: Error = ErrorCode::AccountNotSigner.into();
+        assert_eq!(
+            error.to_str::<CombinedError>(),
+            ErrorCode::AccountNotSigner.to_str()
+        );
+    }
+}
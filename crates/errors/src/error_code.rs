@@ -1,4 +1,7 @@
-use solana_program_error::{ProgramError, ToStr};
+use {
+    crate::ErrorCodes,
+    solana_program_error::{ProgramError, ToStr},
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ErrorCode {
@@ -14,6 +17,18 @@ pub enum ErrorCode {
     InvalidReturnData,
     InvalidDataLength,
     InvalidDataAlignment,
+    TokenMintConstraint,
+    TokenOwnerConstraint,
+    TokenDelegateConstraint,
+    TokenCloseAuthorityConstraint,
+    TokenStateConstraint,
+    TokenAmountConstraint,
+    TokenProgramConstraint,
+    MintAuthorityConstraint,
+    MintFreezeAuthorityConstraint,
+    MintDecimalsConstraint,
+    MintSupplyConstraint,
+    AssociatedTokenConstraint,
 }
 
 impl TryFrom<u32> for ErrorCode {
@@ -33,11 +48,30 @@ impl TryFrom<u32> for ErrorCode {
             109 => Ok(ErrorCode::InvalidReturnData),
             110 => Ok(ErrorCode::InvalidDataLength),
             111 => Ok(ErrorCode::InvalidDataAlignment),
+            112 => Ok(ErrorCode::TokenMintConstraint),
+            113 => Ok(ErrorCode::TokenOwnerConstraint),
+            114 => Ok(ErrorCode::TokenDelegateConstraint),
+            115 => Ok(ErrorCode::TokenCloseAuthorityConstraint),
+            116 => Ok(ErrorCode::TokenStateConstraint),
+            117 => Ok(ErrorCode::TokenAmountConstraint),
+            118 => Ok(ErrorCode::TokenProgramConstraint),
+            119 => Ok(ErrorCode::MintAuthorityConstraint),
+            120 => Ok(ErrorCode::MintFreezeAuthorityConstraint),
+            121 => Ok(ErrorCode::MintDecimalsConstraint),
+            122 => Ok(ErrorCode::MintSupplyConstraint),
+            123 => Ok(ErrorCode::AssociatedTokenConstraint),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
 }
 
+impl ErrorCodes for ErrorCode {
+    const CODES: &'static [u32] = &[
+        100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117,
+        118, 119, 120, 121, 122, 123,
+    ];
+}
+
 impl From<ErrorCode> for ProgramError {
     fn from(e: ErrorCode) -> Self {
         ProgramError::Custom(e as u32)
@@ -63,6 +97,24 @@ impl ToStr for ErrorCode {
             ErrorCode::InvalidReturnData => "Error: The return data is invalid",
             ErrorCode::InvalidDataLength => "Error: Invalid data length",
             ErrorCode::InvalidDataAlignment => "Error: Invalid data alignment",
+            ErrorCode::TokenMintConstraint => "Error: token mint constraint violated",
+            ErrorCode::TokenOwnerConstraint => "Error: token owner constraint violated",
+            ErrorCode::TokenDelegateConstraint => "Error: token delegate constraint violated",
+            ErrorCode::TokenCloseAuthorityConstraint => {
+                "Error: token close authority constraint violated"
+            }
+            ErrorCode::TokenStateConstraint => "Error: token state constraint violated",
+            ErrorCode::TokenAmountConstraint => "Error: token amount constraint violated",
+            ErrorCode::TokenProgramConstraint => "Error: token program constraint violated",
+            ErrorCode::MintAuthorityConstraint => "Error: mint authority constraint violated",
+            ErrorCode::MintFreezeAuthorityConstraint => {
+                "Error: mint freeze authority constraint violated"
+            }
+            ErrorCode::MintDecimalsConstraint => "Error: mint decimals constraint violated",
+            ErrorCode::MintSupplyConstraint => "Error: mint supply constraint violated",
+            ErrorCode::AssociatedTokenConstraint => {
+                "Error: account is not the associated token account of the mint and authority"
+            }
         }
     }
 }
@@ -1,9 +1,21 @@
 #![no_std]
 
+// Lets `TyphoonError` refer to this crate by name in its own tests.
+extern crate self as typhoon_errors;
+
+mod codes;
 mod error_code;
 mod extension;
+mod require;
+
+pub use {codes::*, error_code::*, extension::*, require::*};
+
+/// Items used by `TyphoonError`, so the derive doesn't rely on the caller's imports.
+#[doc(hidden)]
+pub mod __private {
+    pub use solana_program_error::{ProgramError, ToStr};
+}
 
-pub use {error_code::*, extension::*};
 use {
     solana_address::error::AddressError,
     solana_program_error::{ProgramError, ToStr},
@@ -12,6 +24,38 @@ use {
 pub struct Error {
     error: ProgramError,
     account_name: Option<&'static str>,
+    #[cfg(feature = "logging")]
+    context: ErrorContext,
+}
+
+/// Context logged with an error, only tracked with the `logging` feature.
+#[cfg(feature = "logging")]
+#[derive(Default)]
+struct ErrorContext {
+    constraint: Option<&'static str>,
+    instruction: Option<&'static str>,
+    location: Option<(&'static str, u32)>,
+    message: Option<ErrorMessage>,
+}
+
+/// Size of the error values kept to format their message.
+const ERROR_MESSAGE_WORDS: usize = 4;
+
+/// Returns `true` if `T` can be kept by `Error::with_message`, i.e. it fits in 32 bytes with an
+/// alignment of at most 8.
+#[doc(hidden)]
+pub const fn fits_error_message<T>() -> bool {
+    core::mem::size_of::<T>() <= core::mem::size_of::<[u64; ERROR_MESSAGE_WORDS]>()
+        && core::mem::align_of::<T>() <= core::mem::align_of::<u64>()
+}
+
+/// Fields of an error stored with the function formatting its message, so the message is
+/// only formatted when the error is logged.
+#[cfg(feature = "logging")]
+#[derive(Clone, Copy)]
+struct ErrorMessage {
+    value: core::mem::MaybeUninit<[u64; ERROR_MESSAGE_WORDS]>,
+    write: unsafe fn(*const u8, &mut ErrorLogger),
 }
 
 impl Error {
@@ -19,6 +63,8 @@ impl Error {
         Error {
             error: error.into(),
             account_name: None,
+            #[cfg(feature = "logging")]
+            context: ErrorContext::default(),
         }
     }
 
@@ -31,12 +77,102 @@ impl Error {
         self.account_name
     }
 
+    /// Sets the name of the constraint that failed.
+    #[inline(always)]
+    #[cfg_attr(not(feature = "logging"), allow(unused_mut, unused_variables))]
+    pub fn with_constraint(mut self, name: &'static str) -> Self {
+        #[cfg(feature = "logging")]
+        {
+            self.context.constraint = Some(name);
+        }
+        self
+    }
+
+    /// Sets the name of the instruction that failed, if not already set.
+    #[inline(always)]
+    #[cfg_attr(not(feature = "logging"), allow(unused_mut, unused_variables))]
+    pub fn with_instruction(mut self, name: &'static str) -> Self {
+        #[cfg(feature = "logging")]
+        {
+            self.context.instruction.get_or_insert(name);
+        }
+        self
+    }
+
+    /// Sets the source location of the failed check, only kept with the `location` feature.
+    #[inline(always)]
+    #[cfg_attr(not(feature = "location"), allow(unused_mut, unused_variables))]
+    pub fn with_location(mut self, file: &'static str, line: u32) -> Self {
+        #[cfg(feature = "location")]
+        {
+            self.context.location = Some((file, line));
+        }
+        self
+    }
+
+    /// Keeps the `fields` of the error to log the message formatted by `write` with `log_error`.
+    ///
+    /// The fields must fit in 32 bytes with an alignment of at most 8, which is checked at
+    /// compile time.
+    #[cfg(feature = "logging")]
+    #[inline(always)]
+    pub fn with_message<T: 'static>(mut self, fields: T, write: fn(&T, &mut ErrorLogger)) -> Self {
+        const {
+            assert!(
+                fits_error_message::<T>(),
+                "The fields of a formatted error message must fit in 32 bytes."
+            )
+        };
+
+        let mut value = core::mem::MaybeUninit::<[u64; ERROR_MESSAGE_WORDS]>::uninit();
+        // SAFETY: The size and the alignment were checked above. The fields are never dropped.
+        unsafe { value.as_mut_ptr().cast::<T>().write(fields) };
+        self.context.message = Some(ErrorMessage {
+            value,
+            // SAFETY: `&T` and `*const u8` are ABI compatible, the pointer is only called
+            // with the fields stored above.
+            write: unsafe {
+                core::mem::transmute::<
+                    fn(&T, &mut ErrorLogger),
+                    unsafe fn(*const u8, &mut ErrorLogger),
+                >(write)
+            },
+        });
+        self
+    }
+
+    /// Formats the message of the fields kept with `with_message`, if not empty.
+    #[cfg(feature = "logging")]
+    #[cold]
+    pub fn format_message(&self) -> Option<ErrorLogger> {
+        let message = self.context.message.as_ref()?;
+        let mut logger = ErrorLogger::default();
+        // SAFETY: `write` was stored with the type of the fields in `with_message`.
+        unsafe { (message.write)(message.value.as_ptr().cast(), &mut logger) };
+        (!logger.is_empty()).then_some(logger)
+    }
+
+    #[cfg(feature = "logging")]
+    pub fn constraint(&self) -> Option<&str> {
+        self.context.constraint
+    }
+
+    #[cfg(feature = "logging")]
+    pub fn instruction(&self) -> Option<&str> {
+        self.context.instruction
+    }
+
+    #[cfg(feature = "logging")]
+    pub fn location(&self) -> Option<(&str, u32)> {
+        self.context.location
+    }
+
     pub fn to_str<E>(&self) -> &'static str
     where
         E: ToStr + TryFrom<u32> + 'static,
     {
         if let ProgramError::Custom(code) = self.error {
-            if (100..200).contains(&code) {
+            if contains_code(ErrorCode::CODES, code) {
                 return self.error.to_str::<ErrorCode>();
             }
         }
@@ -46,19 +182,13 @@ impl Error {
 
 impl From<ProgramError> for Error {
     fn from(error: ProgramError) -> Self {
-        Error {
-            error,
-            account_name: None,
-        }
+        Error::new(error)
     }
 }
 
 impl From<ErrorCode> for Error {
     fn from(value: ErrorCode) -> Self {
-        Error {
-            error: value.into(),
-            account_name: None,
-        }
+        Error::new(value)
     }
 }
 
@@ -70,10 +200,7 @@ impl From<Error> for ProgramError {
 
 impl From<AddressError> for Error {
     fn from(value: AddressError) -> Self {
-        Self {
-            error: value.into(),
-            account_name: None,
-        }
+        Error::new(value)
     }
 }
 
@@ -86,14 +213,44 @@ pub fn log_error<E>(error: &Error)
 where
     E: ToStr + TryFrom<u32> + 'static,
 {
-    solana_program_log::log(error.to_str::<E>());
+    let context = &error.context;
+    match error.format_message() {
+        Some(message) => message.log(),
+        None => solana_program_log::log(error.to_str::<E>()),
+    }
+
+    if error.account_name.is_none()
+        && context.constraint.is_none()
+        && context.instruction.is_none()
+        && context.location.is_none()
+    {
+        return;
+    }
+
+    let mut logger = solana_program_log::Logger::<200>::default();
+    let mut separator = "";
+    for (key, value) in [
+        ("ix=", context.instruction),
+        ("account=", error.account_name),
+        ("constraint=", context.constraint),
+    ] {
+        if let Some(value) = value {
+            logger.append(separator);
+            logger.append(key);
+            logger.append(value);
+            separator = " ";
+        }
+    }
 
-    if let Some(account_name) = error.account_name() {
-        let mut logger = solana_program_log::Logger::<50>::default();
-        logger.append("Account origin: ");
-        logger.append(unsafe { str::from_utf8_unchecked(account_name.as_bytes()) });
-        logger.log();
+    if let Some((file, line)) = context.location {
+        logger.append(separator);
+        logger.append("at=");
+        logger.append(file);
+        logger.append(":");
+        logger.append(line);
     }
+
+    logger.log();
 }
 
 #[macro_export]
@@ -104,3 +261,82 @@ macro_rules! require {
         }
     };
 }
+
+#[cfg(all(test, feature = "logging"))]
+mod tests {
+    use {
+        super::*, pinocchio::error::ProgramError, solana_address::Address,
+        typhoon_errors_macro::TyphoonError,
+    };
+
+    #[derive(TyphoonError)]
+    #[error_offset = 6000]
+    enum VaultError {
+        #[msg("Error: Vault is locked")]
+        Locked,
+        #[msg("Error: Need {needed}, have {available}")]
+        InsufficientFunds { needed: u64, available: u64 },
+        #[msg("Error: {0} is frozen")]
+        Frozen(bool),
+        #[msg("Error: {0} is not the owner")]
+        NotOwner(Address),
+    }
+
+    fn message(error: &Error) -> Option<ErrorLogger> {
+        error.format_message()
+    }
+
+    #[test]
+    fn test_formatted_message() {
+        let error = Error::from(VaultError::InsufficientFunds {
+            needed: 10,
+            available: 5,
+        });
+        assert_eq!(
+            message(&error).as_deref(),
+            Some(&b"Error: Need 10, have 5"[..])
+        );
+        assert_eq!(ProgramError::from(error), ProgramError::Custom(6001));
+
+        let error = Error::from(VaultError::Frozen(true));
+        assert_eq!(
+            message(&error).as_deref(),
+            Some(&b"Error: true is frozen"[..])
+        );
+        assert_eq!(error.to_str::<VaultError>(), "Error: {0} is frozen");
+
+        let error = Error::from(VaultError::NotOwner(Address::new_from_array([1; 32])));
+        assert_eq!(
+            message(&error).as_deref(),
+            Some(&b"Error: 4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi is not the owner"[..])
+        );
+
+        assert!(message(&Error::from(VaultError::Locked)).is_none());
+        assert!(message(&Error::new(ProgramError::InvalidArgument)).is_none());
+        assert!(matches!(
+            VaultError::try_from(6001),
+            Ok(VaultError::InsufficientFunds {
+                needed: 0,
+                available: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_error_context() {
+        let error = Error::new(ErrorCode::AccountNotSigner)
+            .with_account("payer")
+            .with_constraint("signer")
+            .with_instruction("deposit")
+            .with_instruction("ignored")
+            .with_location("src/lib.rs", 12);
+
+        assert_eq!(error.account_name(), Some("payer"));
+        assert_eq!(error.constraint(), Some("signer"));
+        assert_eq!(error.instruction(), Some("deposit"));
+        #[cfg(feature = "location")]
+        assert_eq!(error.location(), Some(("src/lib.rs", 12)));
+        #[cfg(not(feature = "location"))]
+        assert_eq!(error.location(), None);
+    }
+}
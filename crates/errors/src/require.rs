@@ -0,0 +1,297 @@
+use solana_address::Address;
+#[cfg(feature = "logging")]
+use solana_program_log::Logger;
+
+/// Value that can be logged when a comparison fails.
+///
+/// Required by the comparison macros whatever the features, so that enabling `logging`
+/// doesn't break their callers.
+pub trait LogValue {
+    #[cfg(feature = "logging")]
+    fn append_to<const N: usize>(&self, logger: &mut Logger<N>);
+}
+
+macro_rules! impl_log_value {
+    ($($ty:ty),*) => {
+        $(
+            impl LogValue for $ty {
+                #[cfg(feature = "logging")]
+                #[inline(always)]
+                fn append_to<const N: usize>(&self, logger: &mut Logger<N>) {
+                    logger.append(*self);
+                }
+            }
+        )*
+    };
+}
+
+impl_log_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl LogValue for bool {
+    #[cfg(feature = "logging")]
+    #[inline(always)]
+    fn append_to<const N: usize>(&self, logger: &mut Logger<N>) {
+        logger.append(if *self { "true" } else { "false" });
+    }
+}
+
+impl LogValue for Address {
+    #[cfg(feature = "logging")]
+    #[inline(always)]
+    fn append_to<const N: usize>(&self, logger: &mut Logger<N>) {
+        let mut out = [0u8; five8::BASE58_ENCODED_32_MAX_LEN];
+        let len = five8::encode_32(self.as_array(), &mut out) as usize;
+        // Base58 characters are valid utf8.
+        logger.append(unsafe { core::str::from_utf8_unchecked(&out[..len]) });
+    }
+}
+
+impl LogValue for str {
+    #[cfg(feature = "logging")]
+    #[inline(always)]
+    fn append_to<const N: usize>(&self, logger: &mut Logger<N>) {
+        logger.append(self);
+    }
+}
+
+impl<T: LogValue + ?Sized> LogValue for &T {
+    #[cfg(feature = "logging")]
+    #[inline(always)]
+    fn append_to<const N: usize>(&self, logger: &mut Logger<N>) {
+        (**self).append_to(logger);
+    }
+}
+
+/// Logger used for error messages formatted from the fields of a `TyphoonError`.
+#[cfg(feature = "logging")]
+pub type ErrorLogger = Logger<200>;
+
+/// Logs both operands of a failed comparison.
+#[cfg(feature = "logging")]
+#[cold]
+pub fn log_values(left: &impl LogValue, right: &impl LogValue) {
+    let mut logger = Logger::<64>::default();
+    logger.append("Left: ");
+    left.append_to(&mut logger);
+    logger.log();
+
+    let mut logger = Logger::<64>::default();
+    logger.append("Right: ");
+    right.append_to(&mut logger);
+    logger.log();
+}
+
+#[cfg(feature = "logging")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_values {
+    ( $left:expr, $right:expr ) => {
+        $crate::log_values($left, $right)
+    };
+}
+
+/// Checks that both operands of a comparison implement `LogValue` without logging them.
+#[cfg(not(feature = "logging"))]
+#[doc(hidden)]
+#[inline(always)]
+pub fn assert_log_values(_left: &impl LogValue, _right: &impl LogValue) {}
+
+#[cfg(not(feature = "logging"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_values {
+    ( $left:expr, $right:expr ) => {
+        $crate::assert_log_values($left, $right)
+    };
+}
+
+/// Expands its input only with the `logging` feature of this crate, used by derived code.
+#[cfg(feature = "logging")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_logging {
+    ( $($tokens:tt)* ) => {
+        $($tokens)*
+    };
+}
+
+#[cfg(not(feature = "logging"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_logging {
+    ( $($tokens:tt)* ) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_cmp {
+    ( $left:expr, $right:expr, $error:expr, $op:tt ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if pinocchio::hint::unlikely(left $op right) {
+                    $crate::__log_values!(left, right);
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Returns `$error` if the two values are not equal, logging both with the `logging` feature.
+#[macro_export]
+macro_rules! require_eq {
+    ( $left:expr, $right:expr, $error:expr ) => {
+        $crate::__require_cmp!($left, $right, $error, !=)
+    };
+}
+
+/// Returns `$error` if the two values are equal, logging both with the `logging` feature.
+#[macro_export]
+macro_rules! require_neq {
+    ( $left:expr, $right:expr, $error:expr ) => {
+        $crate::__require_cmp!($left, $right, $error, ==)
+    };
+}
+
+/// Returns `$error` if `$left` is not greater than `$right`, logging both with the `logging` feature.
+#[macro_export]
+macro_rules! require_gt {
+    ( $left:expr, $right:expr, $error:expr ) => {
+        $crate::__require_cmp!($left, $right, $error, <=)
+    };
+}
+
+/// Returns `$error` if `$left` is lower than `$right`, logging both with the `logging` feature.
+#[macro_export]
+macro_rules! require_gte {
+    ( $left:expr, $right:expr, $error:expr ) => {
+        $crate::__require_cmp!($left, $right, $error, <)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_keys {
+    ( $left:expr, $right:expr, $error:expr, $eq:literal ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if pinocchio::hint::unlikely(pinocchio::address::address_eq(left, right) != $eq) {
+                    $crate::__log_values!(left, right);
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Returns `$error` if the two addresses are not equal, logging both in base58 with the
+/// `logging` feature.
+#[macro_export]
+macro_rules! require_keys_eq {
+    ( $left:expr, $right:expr, $error:expr ) => {
+        $crate::__require_keys!($left, $right, $error, true)
+    };
+}
+
+/// Returns `$error` if the two addresses are equal, logging both in base58 with the
+/// `logging` feature.
+#[macro_export]
+macro_rules! require_keys_neq {
+    ( $left:expr, $right:expr, $error:expr ) => {
+        $crate::__require_keys!($left, $right, $error, false)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{Error, ErrorCode},
+        solana_address::Address,
+        solana_program_error::ProgramError,
+    };
+
+    fn check(result: Result<(), Error>) -> Result<(), ProgramError> {
+        result.map_err(ProgramError::from)
+    }
+
+    fn eq(left: u64, right: u64) -> Result<(), Error> {
+        require_eq!(left, right, ErrorCode::AssertConstraint);
+        Ok(())
+    }
+
+    fn neq(left: u64, right: u64) -> Result<(), Error> {
+        require_neq!(left, right, ErrorCode::AssertConstraint);
+        Ok(())
+    }
+
+    fn gt(left: i32, right: i32) -> Result<(), Error> {
+        require_gt!(left, right, ErrorCode::AssertConstraint);
+        Ok(())
+    }
+
+    fn gte(left: i32, right: i32) -> Result<(), Error> {
+        require_gte!(left, right, ErrorCode::AssertConstraint);
+        Ok(())
+    }
+
+    fn keys_eq(left: &Address, right: &Address) -> Result<(), Error> {
+        require_keys_eq!(left, right, ErrorCode::AssertConstraint);
+        Ok(())
+    }
+
+    fn keys_neq(left: &Address, right: &Address) -> Result<(), Error> {
+        require_keys_neq!(*left, *right, ErrorCode::AssertConstraint);
+        Ok(())
+    }
+
+    #[test]
+    fn test_require_cmp() {
+        let error = Err(ErrorCode::AssertConstraint.into());
+
+        assert_eq!(check(eq(1, 1)), Ok(()));
+        assert_eq!(check(eq(1, 2)), error);
+        assert_eq!(check(neq(1, 2)), Ok(()));
+        assert_eq!(check(neq(2, 2)), error);
+        assert_eq!(check(gt(2, -1)), Ok(()));
+        assert_eq!(check(gt(2, 2)), error);
+        assert_eq!(check(gte(2, 2)), Ok(()));
+        assert_eq!(check(gte(-3, 2)), error);
+    }
+
+    #[test]
+    fn test_require_keys() {
+        let error = Err(ErrorCode::AssertConstraint.into());
+        let first = Address::new_from_array([1; 32]);
+        let second = Address::new_from_array([2; 32]);
+
+        assert_eq!(check(keys_eq(&first, &first)), Ok(()));
+        assert_eq!(check(keys_eq(&first, &second)), error);
+        assert_eq!(check(keys_neq(&first, &second)), Ok(()));
+        assert_eq!(check(keys_neq(&second, &second)), error);
+    }
+
+    #[cfg(feature = "logging")]
+    #[test]
+    fn test_log_values() {
+        use {crate::LogValue, solana_program_log::Logger};
+
+        fn logged(value: impl LogValue) -> Logger<64> {
+            let mut logger = Logger::<64>::default();
+            value.append_to(&mut logger);
+            logger
+        }
+
+        assert_eq!(&*logged(42u64), b"42");
+        assert_eq!(&*logged(-7i32), b"-7");
+        assert_eq!(&*logged(true), b"true");
+        assert_eq!(&*logged("left"), b"left");
+        assert_eq!(
+            &*logged(Address::new_from_array([0; 32])),
+            b"11111111111111111111111111111111"
+        );
+        assert_eq!(
+            &*logged(Address::new_from_array([1; 32])),
+            b"4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"
+        );
+    }
+}
@@ -1,15 +1,38 @@
 use {
     base64::{prelude::BASE64_STANDARD, Engine},
     codama::{
-        AccountNode, CamelCaseString, CombineTypesVisitor, ConstantDiscriminatorNode,
-        ConstantValueNode, DefinedTypeNode, DiscriminatorNode, Docs, KorokVisitor, Node, TypeNode,
+        AccountNode, CamelCaseString, CodamaResult, CombineTypesVisitor, ConstantDiscriminatorNode,
+        ConstantValueNode, DefinedTypeNode, DiscriminatorNode, Docs, KorokVisitor, NestedTypeNode,
+        Node, NumberFormat, NumberTypeNode, StructFieldTypeNode, StructTypeNode, TypeNode, U32,
     },
+    hashbrown::HashMap,
     typhoon_discriminator::DiscriminatorBuilder,
-    typhoon_syn::Docs as TyphoonDocs,
+    typhoon_syn::{AccountVersion, Docs as TyphoonDocs, InstructionsList},
 };
 
+/// Item identified by a discriminator.
+///
+/// Accounts and instructions are only compared with items of the same kind, account data and
+/// instruction data never being read in place of each other.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum DiscriminatorKind {
+    Account,
+    Instruction,
+}
+
+impl DiscriminatorKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            DiscriminatorKind::Account => "account",
+            DiscriminatorKind::Instruction => "instruction",
+        }
+    }
+}
+
 pub struct SetAccountVisitor {
     visitor: CombineTypesVisitor,
+    /// Accounts and instructions indexed by their discriminator, the hash part for accounts.
+    discriminators: HashMap<(DiscriminatorKind, Vec<u8>), String>,
 }
 
 impl Default for SetAccountVisitor {
@@ -22,12 +45,114 @@ impl SetAccountVisitor {
     pub fn new() -> Self {
         Self {
             visitor: CombineTypesVisitor::new(),
+            discriminators: HashMap::new(),
         }
     }
+
+    fn register_discriminator(
+        &mut self,
+        kind: DiscriminatorKind,
+        ident: &syn::Ident,
+        discriminator: &[u8],
+    ) -> CodamaResult<()> {
+        let name = ident.to_string();
+
+        let existing = self
+            .discriminators
+            .entry((kind, discriminator.to_vec()))
+            .or_insert_with(|| name.clone());
+
+        if *existing != name {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!(
+                    "Discriminator collision between {kind} `{existing}` and {kind} `{name}`, rename one of them.",
+                    kind = kind.as_str(),
+                ),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn account_node(
+        &mut self,
+        ident: &syn::Ident,
+        attrs: &[syn::Attribute],
+        data: StructTypeNode,
+    ) -> CodamaResult<Node> {
+        let version = AccountVersion::try_from(attrs)?;
+        let dis = DiscriminatorBuilder::new(&ident.to_string())
+            .layout(version.version)
+            .build();
+        self.register_discriminator(DiscriminatorKind::Account, ident, &dis[..4])?;
+
+        Ok(Node::Account(AccountNode {
+            name: CamelCaseString::new(ident.to_string()),
+            size: None,
+            docs: Docs::from(TyphoonDocs::from(attrs).into_vec()),
+            data: NestedTypeNode::Value(data),
+            pda: None,
+            discriminators: vec![DiscriminatorNode::Constant(ConstantDiscriminatorNode::new(
+                ConstantValueNode::bytes(
+                    codama::BytesEncoding::Base64,
+                    BASE64_STANDARD.encode(dis),
+                ),
+                0,
+            ))],
+        }))
+    }
+}
+
+/// Returns the tag encoding of an enum serialized with wincode, `u32` unless overridden.
+fn wincode_tag_size(attrs: &[syn::Attribute]) -> CodamaResult<NumberTypeNode> {
+    let mut format = U32;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("wincode")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag_encoding") {
+                let ty: syn::LitStr = meta.value()?.parse()?;
+                format = NumberFormat::try_from(ty.value())
+                    .map_err(|_| meta.error("Unsupported tag encoding."))?;
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(NumberTypeNode::le(format))
 }
 
 impl KorokVisitor for SetAccountVisitor {
-    fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> codama::CodamaResult<()> {
+    fn visit_const(&mut self, korok: &mut codama_koroks::ConstKorok) -> CodamaResult<()> {
+        let codama::ConstAst::Item(item_const) = korok.ast else {
+            return Ok(());
+        };
+
+        if item_const.ident != "ROUTER" {
+            return Ok(());
+        }
+
+        for (dis, name) in InstructionsList::try_from(item_const)?.0 {
+            let Ok(dis) = u8::try_from(dis) else {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    "Instruction discriminators must fit in a `u8`.",
+                )
+                .into());
+            };
+            self.register_discriminator(DiscriminatorKind::Instruction, &name, &[dis])?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
         if !korok.attributes.has_derive(&[""], "AccountState") {
             return Ok(());
         };
@@ -42,22 +167,32 @@ impl KorokVisitor for SetAccountVisitor {
             return Ok(());
         };
 
-        let dis = DiscriminatorBuilder::new(&korok.ast.ident.to_string()).build();
+        korok.node = Some(self.account_node(&korok.ast.ident, &korok.ast.attrs, ty)?);
 
-        korok.node = Some(Node::Account(AccountNode {
-            name: CamelCaseString::new(korok.ast.ident.to_string()),
-            size: None,
-            docs: Docs::from(TyphoonDocs::from(korok.ast.attrs.as_slice()).into_vec()),
-            data: codama::NestedTypeNode::Value(ty),
-            pda: None,
-            discriminators: vec![DiscriminatorNode::Constant(ConstantDiscriminatorNode::new(
-                ConstantValueNode::bytes(
-                    codama::BytesEncoding::Base64,
-                    BASE64_STANDARD.encode(dis),
-                ),
-                0,
-            ))],
-        }));
+        Ok(())
+    }
+
+    fn visit_enum(&mut self, korok: &mut codama_koroks::EnumKorok) -> CodamaResult<()> {
+        if !korok.attributes.has_derive(&[""], "AccountState") {
+            return Ok(());
+        };
+
+        self.visitor.visit_enum(korok)?;
+
+        let Some(Node::DefinedType(DefinedTypeNode {
+            r#type: TypeNode::Enum(mut ty),
+            ..
+        })) = korok.node.take()
+        else {
+            return Ok(());
+        };
+
+        if korok.attributes.has_derive(&[""], "SchemaRead") {
+            ty.size = NestedTypeNode::Value(wincode_tag_size(&korok.ast.attrs)?);
+        }
+
+        let data = StructTypeNode::new(vec![StructFieldTypeNode::new("state", ty)]);
+        korok.node = Some(self.account_node(&korok.ast.ident, &korok.ast.attrs, data)?);
 
         Ok(())
     }
@@ -67,7 +202,10 @@ impl KorokVisitor for SetAccountVisitor {
 mod tests {
     use {
         super::*,
-        codama::{CodamaResult, IdentifyFieldTypesVisitor, KorokVisitable, Node, StructKorok},
+        codama::{
+            ConstKorok, EnumKorok, EnumVariantTypeNode, IdentifyFieldTypesVisitor, KorokVisitable,
+            Node, StructKorok, U8,
+        },
         syn::{parse_quote, Item},
     };
 
@@ -100,4 +238,124 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_discriminator_collision() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+            #[repr(C)]
+            pub struct Counter {
+                pub count: u64,
+            }
+        };
+
+        let mut visitor = SetAccountVisitor::new();
+        let mut korok = StructKorok::parse(&item)?;
+        korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        korok.accept(&mut visitor)?;
+
+        let dis = DiscriminatorBuilder::new("Counter").build();
+        let other: syn::Ident = parse_quote!(Other);
+        let err = visitor
+            .register_discriminator(DiscriminatorKind::Account, &other, &dis[..4])
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("account `Counter` and account `Other`"));
+
+        let same: syn::Ident = parse_quote!(Counter);
+        assert!(visitor
+            .register_discriminator(DiscriminatorKind::Account, &same, &dis[..4])
+            .is_ok());
+
+        // Instruction discriminators don't collide with account ones.
+        let instruction: syn::Ident = parse_quote!(counter);
+        assert!(visitor
+            .register_discriminator(DiscriminatorKind::Instruction, &instruction, &dis[..4])
+            .is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_discriminator_collision() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            pub const ROUTER: EntryFn = basic_router! {
+                0 => initialize,
+                1 => increment,
+                1 => close,
+            };
+        };
+
+        let mut korok = ConstKorok::parse(&item)?;
+        let err = korok.accept(&mut SetAccountVisitor::new()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("instruction `increment` and instruction `close`"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_visit_enum() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[derive(AccountState, Copy, Clone)]
+            #[repr(u8)]
+            pub enum Order {
+                Open(OpenOrder),
+                Cancelled,
+            }
+        };
+
+        let mut korok = EnumKorok::parse(&item)?;
+        korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        korok.accept(&mut SetAccountVisitor::new())?;
+
+        let Some(Node::Account(account)) = korok.node else {
+            panic!("Expected Account node");
+        };
+
+        assert_eq!(account.name.as_str(), "order");
+        let NestedTypeNode::Value(data) = account.data else {
+            panic!("Expected Struct data");
+        };
+        assert_eq!(data.fields.len(), 1);
+        let TypeNode::Enum(ty) = &data.fields[0].r#type else {
+            panic!("Expected Enum state");
+        };
+        assert_eq!(ty.size, NestedTypeNode::Value(NumberTypeNode::le(U8)));
+        assert!(matches!(ty.variants[0], EnumVariantTypeNode::Tuple(_)));
+        assert!(matches!(ty.variants[1], EnumVariantTypeNode::Empty(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_visit_wincode_enum() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[derive(SchemaRead, SchemaWrite, AccountState)]
+            #[repr(u8)]
+            pub enum Order {
+                Open { price: u64 },
+                Cancelled,
+            }
+        };
+
+        let mut korok = EnumKorok::parse(&item)?;
+        korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        korok.accept(&mut SetAccountVisitor::new())?;
+
+        let Some(Node::Account(account)) = korok.node else {
+            panic!("Expected Account node");
+        };
+        let NestedTypeNode::Value(data) = account.data else {
+            panic!("Expected Struct data");
+        };
+        let TypeNode::Enum(ty) = &data.fields[0].r#type else {
+            panic!("Expected Enum state");
+        };
+        assert_eq!(ty.size, NestedTypeNode::Value(NumberTypeNode::le(U32)));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,193 @@
+use {
+    heck::ToUpperCamelCase,
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote},
+    solana_program_error::ToStr,
+    syn::Ident,
+    typhoon_errors::{ErrorCode, ErrorCodes},
+    typhoon_syn::Errors,
+};
+
+/// Generates a std-only enum for one error type, with its codes and messages.
+fn generate_error_enum(name: &Ident, variants: &[(Ident, u32, String)]) -> TokenStream {
+    let (defs, (from_arms, template_arms)): (Vec<_>, (Vec<_>, Vec<_>)) = variants
+        .iter()
+        .map(|(variant, code, msg)| {
+            (
+                quote!(#variant = #code,),
+                (
+                    quote!(#code => Some(#name::#variant),),
+                    quote!(#name::#variant => #msg,),
+                ),
+            )
+        })
+        .unzip();
+
+    quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum #name {
+            #(#defs)*
+        }
+
+        impl #name {
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    #(#from_arms)*
+                    _ => None,
+                }
+            }
+
+            pub fn code(&self) -> u32 {
+                *self as u32
+            }
+
+            /// The `#[msg]` of the error, with its `{field}` placeholders since the code
+            /// carries no payload.
+            pub fn template(&self) -> &'static str {
+                match self {
+                    #(#template_arms)*
+                }
+            }
+        }
+    }
+}
+
+fn builtin_variants() -> Vec<(Ident, u32, String)> {
+    ErrorCode::CODES
+        .iter()
+        .filter_map(|code| {
+            let error = ErrorCode::try_from(*code).ok()?;
+            Some((
+                format_ident!("{error:?}"),
+                *code,
+                error.to_str().to_string(),
+            ))
+        })
+        .collect()
+}
+
+/// Generates the `errors` module used to decode `InstructionError::Custom` codes off-chain.
+///
+/// The built-in codes are named after the program, e.g. `VaultErrorCode`, to not clash with the
+/// errors of the program.
+pub fn generate_errors(program: &str, errors: &[Errors]) -> TokenStream {
+    let builtin = format_ident!("{}ErrorCode", program.to_upper_camel_case());
+    let mut enums = vec![(builtin, builtin_variants())];
+    enums.extend(errors.iter().map(|errors| {
+        let variants = errors
+            .variants
+            .iter()
+            .map(|v| (v.name.clone(), v.discriminant, v.msg.clone()))
+            .collect();
+        (errors.name.clone(), variants)
+    }));
+
+    let definitions = enums
+        .iter()
+        .map(|(name, variants)| generate_error_enum(name, variants));
+    let names: Vec<_> = enums.iter().map(|(name, _)| name).collect();
+
+    quote! {
+        pub mod errors {
+            #(#definitions)*
+
+            /// Any error the program can return as a custom code.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub enum CustomError {
+                #(#names(#names),)*
+            }
+
+            impl CustomError {
+                pub fn from_code(code: u32) -> Option<Self> {
+                    #(
+                        if let Some(error) = #names::from_code(code) {
+                            return Some(CustomError::#names(error));
+                        }
+                    )*
+                    None
+                }
+
+                pub fn code(&self) -> u32 {
+                    match self {
+                        #(CustomError::#names(error) => error.code(),)*
+                    }
+                }
+
+                pub fn template(&self) -> &'static str {
+                    match self {
+                        #(CustomError::#names(error) => error.template(),)*
+                    }
+                }
+            }
+
+            impl core::fmt::Display for CustomError {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(self.template())
+                }
+            }
+
+            impl std::error::Error for CustomError {}
+
+            /// A custom error code with the context logged by the program.
+            #[derive(Debug, Clone, Default, PartialEq, Eq)]
+            pub struct DecodedError {
+                pub code: u32,
+                pub error: Option<CustomError>,
+                pub instruction: Option<std::string::String>,
+                pub account: Option<std::string::String>,
+                pub constraint: Option<std::string::String>,
+                pub location: Option<(std::string::String, u32)>,
+            }
+
+            impl DecodedError {
+                /// Decodes `code` and parses the context line written by `log_error` from the
+                /// transaction logs, if any.
+                pub fn from_logs<S: AsRef<str>>(code: u32, logs: &[S]) -> Self {
+                    let mut decoded = DecodedError {
+                        code,
+                        error: CustomError::from_code(code),
+                        ..Default::default()
+                    };
+
+                    let Some(line) = logs.iter().rev().find_map(|log| {
+                        let line = log.as_ref().strip_prefix("Program log: ")?;
+                        line.split(' ')
+                            .all(|token| {
+                                ["ix=", "account=", "constraint=", "at="]
+                                    .iter()
+                                    .any(|key| token.starts_with(key))
+                            })
+                            .then_some(line)
+                    }) else {
+                        return decoded;
+                    };
+
+                    for token in line.split(' ') {
+                        let Some((key, value)) = token.split_once('=') else {
+                            continue;
+                        };
+                        let value = std::string::String::from(value);
+                        match key {
+                            "ix" => decoded.instruction = Some(value),
+                            "account" => decoded.account = Some(value),
+                            "constraint" => decoded.constraint = Some(value),
+                            "at" => {
+                                decoded.location = value
+                                    .rsplit_once(':')
+                                    .and_then(|(file, line)| Some((file.into(), line.parse().ok()?)));
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    decoded
+                }
+
+                pub fn template(&self) -> Option<&'static str> {
+                    self.error.as_ref().map(CustomError::template)
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 mod client;
 mod cpi;
+mod errors;
 
-pub use {client::*, cpi::*};
+pub use {client::*, cpi::*, errors::*};
 use {
     hashbrown::HashMap,
     proc_macro2::TokenStream,
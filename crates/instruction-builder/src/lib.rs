@@ -1,6 +1,6 @@
 use {
     crate::{
-        generator::{ClientGenerator, CpiGenerator, Generator},
+        generator::{generate_errors, ClientGenerator, CpiGenerator, Generator},
         resolver::Resolver,
     },
     cargo_manifest::{Dependency, Manifest},
@@ -9,8 +9,13 @@ use {
     proc_macro2::{Span, TokenStream},
     quote::{format_ident, quote, ToTokens},
     std::path::Path,
-    syn::{parse::Parse, parse_macro_input, visit::Visit, Ident, Item, Token},
-    typhoon_syn::{Argument, Arguments, Context, Instruction, InstructionArg, InstructionsList},
+    syn::{
+        parse::Parse, parse_macro_input, punctuated::Punctuated, visit::Visit, Attribute, Ident,
+        Item, Path as SynPath, Token,
+    },
+    typhoon_syn::{
+        Argument, Arguments, Context, Errors, Instruction, InstructionArg, InstructionsList,
+    },
 };
 
 mod generator;
@@ -21,10 +26,10 @@ mod resolver;
 pub fn generate_instructions_client(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let instructions = parse_macro_input!(input as GeneratorContext);
 
-    instructions
-        .generate::<ClientGenerator>()
-        .into_token_stream()
-        .into()
+    let client = instructions.generate::<ClientGenerator>();
+    let errors = generate_errors(&instructions.program, &instructions.errors);
+
+    quote!(#client #errors).into()
 }
 
 #[proc_macro]
@@ -39,19 +44,26 @@ pub fn generate_cpi_client(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
 #[derive(Default)]
 struct GeneratorContext {
+    pub program: String,
     pub instructions: HashMap<usize, Instruction>,
     pub context: HashMap<String, Context>,
     pub arg_structs: HashMap<String, Vec<Argument>>,
+    pub errors: Vec<Errors>,
 }
 
 impl GeneratorContext {
     pub fn from_resolver(
+        program: String,
         ix_list: InstructionsList,
         resolver: Resolver,
         filter: Option<HashSet<Ident>>,
     ) -> Self {
-        let mut gen = GeneratorContext::default();
-        let (mut instructions_map, mut contexts_map) = Self::parse_items(&resolver.items);
+        let mut gen = GeneratorContext {
+            program,
+            ..Default::default()
+        };
+        let (mut instructions_map, mut contexts_map, errors) = Self::parse_items(&resolver.items);
+        gen.errors = errors;
 
         for (index, ident) in ix_list.0 {
             if let Some(ref filter_set) = filter {
@@ -84,9 +96,16 @@ impl GeneratorContext {
         gen
     }
 
-    fn parse_items(items: &[Item]) -> (HashMap<Ident, Instruction>, HashMap<String, Context>) {
+    fn parse_items(
+        items: &[Item],
+    ) -> (
+        HashMap<Ident, Instruction>,
+        HashMap<String, Context>,
+        Vec<Errors>,
+    ) {
         let mut instructions = HashMap::new();
         let mut contexts = HashMap::new();
+        let mut errors = Vec::new();
 
         for item in items {
             match item {
@@ -100,11 +119,16 @@ impl GeneratorContext {
                         contexts.insert(ctx.name.to_string(), ctx);
                     }
                 }
+                Item::Enum(item_enum) if has_derive(&item_enum.attrs, "TyphoonError") => {
+                    if let Ok(errs) = Errors::try_from(item_enum) {
+                        errors.push(errs);
+                    }
+                }
                 _ => continue,
             }
         }
 
-        (instructions, contexts)
+        (instructions, contexts, errors)
     }
 
     pub fn generate<T: Generator>(&self) -> TokenStream {
@@ -156,13 +180,35 @@ impl Parse for GeneratorContext {
             let _ = syn::bracketed!(content in input);
             let idents = content.parse_terminated(Ident::parse, Token![,])?;
             let instructions = HashSet::from_iter(idents);
-            Ok(Self::from_resolver(ix_list, resolver, Some(instructions)))
+            Ok(Self::from_resolver(
+                crate_name,
+                ix_list,
+                resolver,
+                Some(instructions),
+            ))
         } else {
-            Ok(Self::from_resolver(ix_list, resolver, None))
+            Ok(Self::from_resolver(crate_name, ix_list, resolver, None))
         }
     }
 }
 
+/// Returns `true` if one of the `#[derive]` attributes contains `derive_name`.
+fn has_derive(attrs: &[Attribute], derive_name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<SynPath, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == derive_name)
+        })
+}
+
 fn read_and_parse_file(source_file: impl AsRef<Path>) -> syn::Result<syn::File> {
     let file_content = std::fs::read_to_string(&source_file)
         .map_err(|err| syn::Error::new(Span::call_site(), err.to_string()))?;
@@ -193,3 +239,32 @@ fn get_package_path(manifest: &Manifest, crate_name: &str) -> Option<String> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, syn::parse_quote};
+
+    #[test]
+    fn parse_only_derived_errors() {
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(typhoon::TyphoonError)]
+                pub enum VaultError {
+                    #[msg("Error: Vault is locked")]
+                    Locked = 6000,
+                }
+            },
+            parse_quote! {
+                #[derive(Clone, Copy)]
+                pub enum Side {
+                    Bid,
+                    Ask,
+                }
+            },
+        ];
+
+        let (_, _, errors) = GeneratorContext::parse_items(&items);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].name, "VaultError");
+    }
+}
@@ -0,0 +1,25 @@
+use typhoon::prelude::*;
+
+#[derive(TyphoonError)]
+#[error_offset = 6000]
+pub enum VaultError {
+    #[msg("Error: Vault is locked")]
+    Locked,
+    #[msg("Error: Need {needed}, have {available}")]
+    InsufficientFunds { needed: u64, available: u64 },
+}
+
+// Named like the built-in codes, which the client names after the program.
+#[derive(TyphoonError)]
+#[error_offset = 7000]
+pub enum ErrorCode {
+    #[msg("Error: Program is paused")]
+    Paused,
+}
+
+// Not an error, its variants must not be decoded from codes 0 and 1.
+#[derive(Clone, Copy)]
+pub enum Side {
+    Bid,
+    Ask,
+}
@@ -0,0 +1,63 @@
+use typhoon_instruction_builder::generate_instructions_client;
+
+generate_instructions_client!(error_program);
+
+use errors::{CustomError, DecodedError, ErrorCode, ErrorProgramErrorCode, VaultError};
+
+#[test]
+fn test_decode_codes() {
+    assert_eq!(
+        CustomError::from_code(6000),
+        Some(CustomError::VaultError(VaultError::Locked))
+    );
+    assert_eq!(
+        CustomError::from_code(6001).map(|error| error.template()),
+        Some("Error: Need {needed}, have {available}")
+    );
+    assert_eq!(
+        CustomError::from_code(101),
+        Some(CustomError::ErrorProgramErrorCode(
+            ErrorProgramErrorCode::AccountNotSigner
+        ))
+    );
+    assert_eq!(
+        CustomError::from_code(7000),
+        Some(CustomError::ErrorCode(ErrorCode::Paused))
+    );
+    assert_eq!(CustomError::from_code(6001).unwrap().code(), 6001);
+    assert_eq!(CustomError::from_code(0), None);
+    assert_eq!(CustomError::from_code(6002), None);
+}
+
+#[test]
+fn test_from_logs() {
+    let logs = [
+        "Program Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS invoke [1]",
+        "Program log: Error: Vault is locked",
+        "Program log: ix=Withdraw account=vault constraint=has_one at=src/lib.rs:42",
+        "Program Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS failed: custom program error: 0x1770",
+    ];
+
+    let decoded = DecodedError::from_logs(6000, &logs);
+    assert_eq!(
+        decoded,
+        DecodedError {
+            code: 6000,
+            error: Some(CustomError::VaultError(VaultError::Locked)),
+            instruction: Some("Withdraw".into()),
+            account: Some("vault".into()),
+            constraint: Some("has_one".into()),
+            location: Some(("src/lib.rs".into(), 42)),
+        }
+    );
+    assert_eq!(decoded.template(), Some("Error: Vault is locked"));
+
+    // The message alone is not a context line.
+    let decoded = DecodedError::from_logs(6000, &logs[..2]);
+    assert_eq!(decoded.instruction, None);
+    assert_eq!(decoded.location, None);
+
+    let decoded = DecodedError::from_logs::<&str>(1, &[]);
+    assert_eq!(decoded.error, None);
+    assert_eq!(decoded.template(), None);
+}
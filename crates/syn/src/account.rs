@@ -21,6 +21,8 @@ pub struct InstructionAccount {
     pub constraints: Constraints,
     pub meta: AccountMeta,
     pub docs: Vec<String>,
+    /// The account is wrapped in `Lazy`, its checks run when it is loaded.
+    pub is_lazy: bool,
     ty: Option<PathSegment>,
     pub inner_ty: Ident,
 }
@@ -44,6 +46,7 @@ impl TryFrom<&Field> for InstructionAccount {
             docs: Docs::from(value.attrs.as_slice()).into_vec(),
             constraints: Constraints::try_from(value.attrs.as_slice())?,
             meta: AccountMeta::default(),
+            is_lazy: false,
             ty: None,
             inner_ty: Ident::new("UncheckedAccount", Span::call_site()),
         };
@@ -66,6 +69,12 @@ impl Visit<'_> for InstructionAccount {
                 self.ty = Some(i.clone());
             }
             visit_path_segment(self, i);
+        } else if ty == "Lazy" {
+            self.is_lazy = true;
+            if self.ty.is_none() {
+                self.ty = Some(i.clone());
+            }
+            visit_path_segment(self, i);
         } else if ty.ends_with("Signer") {
             self.meta.is_signer = true;
             if self.ty.is_none() {
@@ -116,6 +125,14 @@ mod tests {
         assert!(!account.meta.is_optional);
         assert!(!account.meta.is_signer);
 
+        let field: syn::Field =
+            parse_quote!(pub random2: Mut<Lazy<'info, Account<'info, Random2>>>);
+        let account = InstructionAccount::try_from(&field).unwrap();
+        assert_eq!(account.inner_ty, "Random2");
+        assert!(account.is_lazy);
+        assert!(account.meta.is_mutable);
+        assert!(!account.meta.is_signer);
+
         let field: syn::Field = parse_quote!(pub random2: UncheckedAccount);
         let account = InstructionAccount::try_from(&field).unwrap();
         assert_eq!(account.inner_ty, "UncheckedAccount");
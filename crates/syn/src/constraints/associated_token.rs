@@ -4,6 +4,7 @@ use syn::{parse::Parse, Ident, Token};
 pub enum ConstraintAssociatedToken {
     Mint(Ident),
     Authority(Ident),
+    TokenProgram(Ident),
 }
 
 impl Parse for ConstraintAssociatedToken {
@@ -21,6 +22,11 @@ impl Parse for ConstraintAssociatedToken {
 
                 Ok(ConstraintAssociatedToken::Authority(input.parse()?))
             }
+            "token_program" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintAssociatedToken::TokenProgram(input.parse()?))
+            }
             _ => Err(syn::Error::new(
                 input.span(),
                 "Invalid variant for the token constraint.",
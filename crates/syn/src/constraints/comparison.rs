@@ -0,0 +1,40 @@
+use syn::{parse::Parse, BinOp, Expr, Token};
+
+/// Comparison of a value read from the account, written `= expr` or with one of `==`, `!=`,
+/// `<`, `<=`, `>` and `>=`.
+#[derive(Clone)]
+pub struct ConstraintComparison {
+    pub op: BinOp,
+    pub value: Expr,
+}
+
+impl Parse for ConstraintComparison {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let op = if input.peek(Token![=]) && !input.peek(Token![==]) {
+            let eq = input.parse::<Token![=]>()?;
+            BinOp::Eq(Token![==](eq.spans[0]))
+        } else {
+            let op = input.parse::<BinOp>()?;
+            if !matches!(
+                op,
+                BinOp::Eq(_)
+                    | BinOp::Ne(_)
+                    | BinOp::Lt(_)
+                    | BinOp::Le(_)
+                    | BinOp::Gt(_)
+                    | BinOp::Ge(_)
+            ) {
+                return Err(syn::Error::new_spanned(
+                    op,
+                    "Expected one of `=`, `==`, `!=`, `<`, `<=`, `>` or `>=`.",
+                ));
+            }
+            op
+        };
+
+        Ok(ConstraintComparison {
+            op,
+            value: input.parse()?,
+        })
+    }
+}
@@ -1,10 +1,31 @@
-use syn::{parse::Parse, Expr, Ident, Token};
+use {
+    super::ConstraintComparison,
+    syn::{parse::Parse, Expr, Ident, Token},
+};
 
 #[derive(Clone)]
 pub enum ConstraintMint {
     Authority(Expr),
     Decimals(Expr),
     FreezeAuthority(Expr),
+    Supply(ConstraintComparison),
+    Extension(ConstraintMintExtension),
+}
+
+/// Token-2022 extension initialized with the mint, see `mint::extensions::...`.
+#[derive(Clone)]
+pub enum ConstraintMintExtension {
+    TransferFeeConfigAuthority(Expr),
+    WithdrawWithheldAuthority(Expr),
+    TransferFeeBasisPoints(Expr),
+    MaximumFee(Expr),
+    CloseAuthority(Expr),
+    NonTransferable,
+    InterestRateAuthority(Expr),
+    InterestRate(Expr),
+    PermanentDelegate(Expr),
+    MetadataPointerAuthority(Expr),
+    MetadataAddress(Expr),
 }
 
 impl Parse for ConstraintMint {
@@ -26,6 +47,8 @@ impl Parse for ConstraintMint {
                 input.parse::<Token![=]>()?;
                 Ok(ConstraintMint::FreezeAuthority(input.parse()?))
             }
+            "supply" => Ok(ConstraintMint::Supply(input.parse()?)),
+            "extensions" => Ok(ConstraintMint::Extension(input.parse()?)),
             _ => Err(syn::Error::new(
                 input.span(),
                 "Invalid variant for the token constraint.",
@@ -33,3 +56,48 @@ impl Parse for ConstraintMint {
         }
     }
 }
+
+impl Parse for ConstraintMintExtension {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![::]>()?;
+        let extension = input.parse::<Ident>()?;
+        if extension == "non_transferable" {
+            return Ok(ConstraintMintExtension::NonTransferable);
+        }
+
+        input.parse::<Token![::]>()?;
+        let field = input.parse::<Ident>()?;
+        let variant: fn(Expr) -> Self =
+            match (extension.to_string().as_str(), field.to_string().as_str()) {
+                ("transfer_fee", "config_authority") => {
+                    ConstraintMintExtension::TransferFeeConfigAuthority
+                }
+                ("transfer_fee", "withdraw_withheld_authority") => {
+                    ConstraintMintExtension::WithdrawWithheldAuthority
+                }
+                ("transfer_fee", "basis_points") => ConstraintMintExtension::TransferFeeBasisPoints,
+                ("transfer_fee", "maximum_fee") => ConstraintMintExtension::MaximumFee,
+                ("close_authority", "authority") => ConstraintMintExtension::CloseAuthority,
+                ("interest_bearing", "rate_authority") => {
+                    ConstraintMintExtension::InterestRateAuthority
+                }
+                ("interest_bearing", "rate") => ConstraintMintExtension::InterestRate,
+                ("permanent_delegate", "delegate") => ConstraintMintExtension::PermanentDelegate,
+                ("metadata_pointer", "authority") => {
+                    ConstraintMintExtension::MetadataPointerAuthority
+                }
+                ("metadata_pointer", "metadata_address") => {
+                    ConstraintMintExtension::MetadataAddress
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        "Invalid variant for the mint extensions constraint.",
+                    ))
+                }
+            };
+        input.parse::<Token![=]>()?;
+
+        Ok(variant(input.parse()?))
+    }
+}
@@ -7,6 +7,7 @@ mod address;
 mod assert;
 mod associated_token;
 mod bump;
+mod comparison;
 mod has_one;
 mod init;
 mod init_if_needed;
@@ -19,8 +20,8 @@ mod space;
 mod token;
 
 pub use {
-    address::*, assert::*, associated_token::*, bump::*, has_one::*, init::*, init_if_needed::*,
-    mint::*, payer::*, program::*, seeded::*, seeds::*, space::*, token::*,
+    address::*, assert::*, associated_token::*, bump::*, comparison::*, has_one::*, init::*,
+    init_if_needed::*, mint::*, payer::*, program::*, seeded::*, seeds::*, space::*, token::*,
 };
 
 pub const CONSTRAINT_IDENT_STR: &str = "constraint";
@@ -146,4 +147,78 @@ mod tests {
 
         assert_eq!(constraints.0.len(), 9);
     }
+
+    #[test]
+    fn test_parse_token_checks() {
+        let attributes: Vec<syn::Attribute> = parse_quote! {
+            #[constraint(
+                token::delegate = delegate,
+                token::is_frozen = false,
+                token::amount >= args.amount,
+                token::token_program = token_program,
+                mint::supply = 0,
+                associated_token::token_program = token_program,
+            )]
+        };
+
+        let constraints = Constraints::try_from(attributes.as_slice()).unwrap();
+        assert!(matches!(
+            constraints.0.as_slice(),
+            [
+                Constraint::Token(ConstraintToken::Delegate(_)),
+                Constraint::Token(ConstraintToken::IsFrozen(_)),
+                Constraint::Token(ConstraintToken::Amount(ConstraintComparison {
+                    op: syn::BinOp::Ge(_),
+                    ..
+                })),
+                Constraint::Token(ConstraintToken::TokenProgram(_)),
+                Constraint::Mint(ConstraintMint::Supply(ConstraintComparison {
+                    op: syn::BinOp::Eq(_),
+                    ..
+                })),
+                Constraint::AssociatedToken(ConstraintAssociatedToken::TokenProgram(_)),
+            ]
+        ));
+
+        let attributes: Vec<syn::Attribute> = parse_quote! {
+            #[constraint(token::amount += 1)]
+        };
+        assert!(Constraints::try_from(attributes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_parse_mint_extensions() {
+        let attributes: Vec<syn::Attribute> = parse_quote! {
+            #[constraint(
+                mint::extensions::transfer_fee::basis_points = 50,
+                mint::extensions::transfer_fee::maximum_fee = args.maximum_fee,
+                mint::extensions::non_transferable,
+                mint::extensions::metadata_pointer::metadata_address = mint.address(),
+            )]
+        };
+
+        let constraints = Constraints::try_from(attributes.as_slice()).unwrap();
+        assert!(matches!(
+            constraints.0.as_slice(),
+            [
+                Constraint::Mint(ConstraintMint::Extension(
+                    ConstraintMintExtension::TransferFeeBasisPoints(_)
+                )),
+                Constraint::Mint(ConstraintMint::Extension(
+                    ConstraintMintExtension::MaximumFee(_)
+                )),
+                Constraint::Mint(ConstraintMint::Extension(
+                    ConstraintMintExtension::NonTransferable
+                )),
+                Constraint::Mint(ConstraintMint::Extension(
+                    ConstraintMintExtension::MetadataAddress(_)
+                )),
+            ]
+        ));
+
+        let attributes: Vec<syn::Attribute> = parse_quote! {
+            #[constraint(mint::extensions::transfer_fee::rate = 1)]
+        };
+        assert!(Constraints::try_from(attributes.as_slice()).is_err());
+    }
 }
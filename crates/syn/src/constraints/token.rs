@@ -1,9 +1,17 @@
-use syn::{parse::Parse, Expr, Ident, Token};
+use {
+    super::ConstraintComparison,
+    syn::{parse::Parse, Expr, Ident, Token},
+};
 
 #[derive(Clone)]
 pub enum ConstraintToken {
     Mint(Ident),
     Owner(Expr),
+    Delegate(Expr),
+    CloseAuthority(Expr),
+    IsFrozen(Expr),
+    Amount(ConstraintComparison),
+    TokenProgram(Ident),
 }
 
 impl Parse for ConstraintToken {
@@ -21,6 +29,27 @@ impl Parse for ConstraintToken {
 
                 Ok(ConstraintToken::Owner(input.parse()?))
             }
+            "delegate" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintToken::Delegate(input.parse()?))
+            }
+            "close_authority" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintToken::CloseAuthority(input.parse()?))
+            }
+            "is_frozen" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintToken::IsFrozen(input.parse()?))
+            }
+            "amount" => Ok(ConstraintToken::Amount(input.parse()?)),
+            "token_program" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintToken::TokenProgram(input.parse()?))
+            }
             _ => Err(syn::Error::new(
                 input.span(),
                 "Invalid variant for the token constraint.",
@@ -1,8 +1,10 @@
 use syn::{
     parse::Parse, punctuated::Punctuated, token::Comma, Attribute, Data, DeriveInput, Expr,
-    ExprLit, Ident, ItemEnum, Lit, LitStr, Variant,
+    ExprLit, Fields, Ident, ItemEnum, Lit, LitStr, Meta, MetaNameValue, Variant,
 };
 
+const ERROR_OFFSET_IDENT_STR: &str = "error_offset";
+
 fn parse_attribute(attributes: &[Attribute]) -> Option<String> {
     attributes.iter().find_map(|attr| {
         if !attr.path().is_ident("msg") {
@@ -15,9 +17,77 @@ fn parse_attribute(attributes: &[Attribute]) -> Option<String> {
 }
 
 pub struct ErrorVariant {
+    /// The error code, including the offset of the enum.
     pub discriminant: u32,
     pub name: Ident,
     pub msg: String,
+    pub fields: Fields,
+    /// The message split on its `{field}` placeholders.
+    pub segments: Vec<MsgSegment>,
+}
+
+impl ErrorVariant {
+    /// Returns true if the message interpolates any field.
+    pub fn is_formatted(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, MsgSegment::Field(_)))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MsgSegment {
+    Lit(String),
+    /// Index of the interpolated field in the variant.
+    Field(usize),
+}
+
+/// Splits `msg` on its `{field}` placeholders, `{{` and `}}` being escaped braces.
+fn parse_segments(msg: &str, fields: &Fields) -> Result<Vec<MsgSegment>, String> {
+    let mut segments = Vec::new();
+    let mut lit = String::new();
+    let mut chars = msg.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                lit.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                lit.push('}');
+            }
+            '{' => {
+                let (name, rest) = chars
+                    .as_str()
+                    .split_once('}')
+                    .ok_or("Unclosed `{` in the error message.")?;
+                let index = fields
+                    .iter()
+                    .enumerate()
+                    .position(|(i, field)| match &field.ident {
+                        Some(ident) => ident == name,
+                        None => name.parse() == Ok(i),
+                    })
+                    .ok_or_else(|| format!("Unknown field `{name}` in the error message."))?;
+
+                if !lit.is_empty() {
+                    segments.push(MsgSegment::Lit(core::mem::take(&mut lit)));
+                }
+                segments.push(MsgSegment::Field(index));
+                chars = rest.chars();
+            }
+            '}' => return Err("Unmatched `}` in the error message.".to_string()),
+            c => lit.push(c),
+        }
+    }
+
+    if !lit.is_empty() {
+        segments.push(MsgSegment::Lit(lit));
+    }
+
+    Ok(segments)
 }
 
 pub struct Errors {
@@ -31,7 +101,7 @@ impl TryFrom<&ItemEnum> for Errors {
     fn try_from(value: &ItemEnum) -> Result<Self, Self::Error> {
         Ok(Errors {
             name: value.ident.clone(),
-            variants: parse_variants(&value.variants)?,
+            variants: parse_variants(&value.attrs, &value.variants)?,
         })
     }
 }
@@ -49,13 +119,42 @@ impl Parse for Errors {
 
         Ok(Errors {
             name: derive_input.ident,
-            variants: parse_variants(&data.variants)?,
+            variants: parse_variants(&derive_input.attrs, &data.variants)?,
         })
     }
 }
 
-fn parse_variants(data_variants: &Punctuated<Variant, Comma>) -> syn::Result<Vec<ErrorVariant>> {
-    let mut variants = Vec::with_capacity(data_variants.len());
+/// Parses the `#[error_offset = N]` attribute, defaulting to 0.
+fn parse_offset(attributes: &[Attribute]) -> syn::Result<u32> {
+    let Some(attr) = attributes
+        .iter()
+        .find(|attr| attr.path().is_ident(ERROR_OFFSET_IDENT_STR))
+    else {
+        return Ok(0);
+    };
+
+    match &attr.meta {
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(offset),
+                    ..
+                }),
+            ..
+        }) => offset.base10_parse(),
+        _ => Err(syn::Error::new_spanned(
+            attr,
+            "Expected `#[error_offset = <u32>]`.",
+        )),
+    }
+}
+
+fn parse_variants(
+    attributes: &[Attribute],
+    data_variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<Vec<ErrorVariant>> {
+    let offset = parse_offset(attributes)?;
+    let mut variants: Vec<ErrorVariant> = Vec::with_capacity(data_variants.len());
     let mut latest_dis: isize = -1;
 
     for variant in data_variants {
@@ -76,12 +175,94 @@ fn parse_variants(data_variants: &Punctuated<Variant, Comma>) -> syn::Result<Vec
             latest_dis += 1;
         }
 
+        let discriminant = u32::try_from(latest_dis)
+            .ok()
+            .and_then(|dis| dis.checked_add(offset))
+            .ok_or(syn::Error::new_spanned(
+                variant,
+                "The error code overflows a u32.",
+            ))?;
+
+        if let Some(existing) = variants.iter().find(|v| v.discriminant == discriminant) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!(
+                    "The error code {discriminant} is already used by `{}`.",
+                    existing.name
+                ),
+            ));
+        }
+
+        let segments = parse_segments(&msg, &variant.fields)
+            .map_err(|err| syn::Error::new_spanned(variant, err))?;
+
         variants.push(ErrorVariant {
             name: variant_name.to_owned(),
             msg,
-            discriminant: latest_dis as u32,
+            discriminant,
+            fields: variant.fields.clone(),
+            segments,
         });
     }
 
     Ok(variants)
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, syn::parse_quote};
+
+    #[test]
+    fn test_error_offset() {
+        let item: ItemEnum = parse_quote! {
+            #[error_offset = 6000]
+            pub enum MyError {
+                #[msg("First")]
+                First,
+                #[msg("Second")]
+                Second = 5,
+            }
+        };
+
+        let errors = Errors::try_from(&item).unwrap();
+        let codes: Vec<_> = errors.variants.iter().map(|v| v.discriminant).collect();
+        assert_eq!(codes, [6000, 6005]);
+    }
+
+    #[test]
+    fn test_formatted_msg() {
+        let item: ItemEnum = parse_quote! {
+            pub enum MyError {
+                #[msg("Need {needed}, have {available} {{units}}")]
+                InsufficientFunds { needed: u64, available: u64 },
+                #[msg("Invalid {0}")]
+                Invalid(u8),
+                #[msg("Unknown {missing}")]
+                Unknown { field: u8 },
+            }
+        };
+
+        let err = Errors::try_from(&item).err().unwrap();
+        assert!(err.to_string().contains("Unknown field `missing`"));
+
+        let valid = Punctuated::from_iter(item.variants.into_iter().take(2));
+        let errors = parse_variants(&item.attrs, &valid).unwrap();
+        assert_eq!(
+            errors[0].segments,
+            [
+                MsgSegment::Lit("Need ".to_string()),
+                MsgSegment::Field(0),
+                MsgSegment::Lit(", have ".to_string()),
+                MsgSegment::Field(1),
+                MsgSegment::Lit(" {units}".to_string()),
+            ]
+        );
+        assert_eq!(
+            errors[1].segments,
+            [
+                MsgSegment::Lit("Invalid ".to_string()),
+                MsgSegment::Field(0)
+            ]
+        );
+    }
+}
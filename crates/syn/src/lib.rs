@@ -10,5 +10,8 @@ mod doc;
 mod errors;
 mod instruction;
 mod macros;
+mod version;
 
-pub use {account::*, arguments::*, context::*, data::*, doc::*, errors::*, instruction::*};
+pub use {
+    account::*, arguments::*, context::*, data::*, doc::*, errors::*, instruction::*, version::*,
+};
@@ -0,0 +1,96 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Attribute, Ident, LitInt, Path, Token,
+};
+
+pub const VERSION_IDENT_STR: &str = "version";
+
+/// Layout version of an account state, set with `#[version(2, migrate_from = CounterV1)]`.
+pub struct AccountVersion {
+    pub version: u8,
+    pub migrate_from: Option<Path>,
+}
+
+impl Default for AccountVersion {
+    fn default() -> Self {
+        AccountVersion {
+            version: 1,
+            migrate_from: None,
+        }
+    }
+}
+
+impl AccountVersion {
+    /// Version of the layout accounts are migrated from.
+    pub fn previous_version(&self) -> Option<u8> {
+        self.migrate_from.as_ref().map(|_| self.version - 1)
+    }
+}
+
+impl Parse for AccountVersion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit: LitInt = input.parse()?;
+        let version = lit.base10_parse::<u8>()?;
+        if version == 0 {
+            return Err(syn::Error::new_spanned(lit, "The version starts at 1."));
+        }
+
+        let mut migrate_from = None;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let name: Ident = input.parse()?;
+            if name != "migrate_from" {
+                return Err(syn::Error::new_spanned(name, "Unknown version argument."));
+            }
+            input.parse::<Token![=]>()?;
+            migrate_from = Some(input.parse()?);
+
+            if version == 1 {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "The first version cannot be migrated from another layout.",
+                ));
+            }
+        }
+
+        Ok(AccountVersion {
+            version,
+            migrate_from,
+        })
+    }
+}
+
+impl TryFrom<&[Attribute]> for AccountVersion {
+    type Error = syn::Error;
+
+    fn try_from(value: &[Attribute]) -> Result<Self, Self::Error> {
+        value
+            .iter()
+            .find(|attr| attr.path().is_ident(VERSION_IDENT_STR))
+            .map(|attr| attr.parse_args())
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, syn::parse_quote};
+
+    #[test]
+    fn test_parse_version() {
+        let attrs: Vec<Attribute> = parse_quote!(#[derive(AccountState)]);
+        let version = AccountVersion::try_from(attrs.as_slice()).unwrap();
+        assert_eq!(version.version, 1);
+        assert!(version.previous_version().is_none());
+
+        let attrs: Vec<Attribute> = parse_quote!(#[version(3, migrate_from = CounterV2)]);
+        let version = AccountVersion::try_from(attrs.as_slice()).unwrap();
+        assert_eq!(version.version, 3);
+        assert_eq!(version.previous_version(), Some(2));
+        assert!(version.migrate_from.unwrap().is_ident("CounterV2"));
+
+        let attrs: Vec<Attribute> = parse_quote!(#[version(1, migrate_from = CounterV0)]);
+        assert!(AccountVersion::try_from(attrs.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use {
+    super::ExtensionType,
+    crate::invoke,
+    pinocchio::{instruction::InstructionAccount, AccountView},
+    solana_address::Address,
+    typhoon_errors::Error,
+};
+
+/// Writes an optional address as a one byte tag followed by the address when set.
+#[inline(always)]
+fn write_option(dst: &mut [u8], address: Option<&Address>) -> usize {
+    match address {
+        Some(address) => {
+            dst[0] = 1;
+            dst[1..33].copy_from_slice(address.as_ref());
+            33
+        }
+        None => {
+            dst[0] = 0;
+            1
+        }
+    }
+}
+
+/// Writes an optional address stored as zeros when unset.
+#[inline(always)]
+fn write_optional_address(dst: &mut [u8], address: Option<&Address>) {
+    if let Some(address) = address {
+        dst[..32].copy_from_slice(address.as_ref());
+    }
+}
+
+/// Extension initialized on a mint after its account is created and before `InitializeMint2`.
+pub enum MintExtensionConfig<'a> {
+    TransferFeeConfig {
+        transfer_fee_config_authority: Option<&'a Address>,
+        withdraw_withheld_authority: Option<&'a Address>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    MintCloseAuthority {
+        close_authority: Option<&'a Address>,
+    },
+    NonTransferable,
+    InterestBearingConfig {
+        rate_authority: Option<&'a Address>,
+        rate: i16,
+    },
+    PermanentDelegate {
+        delegate: &'a Address,
+    },
+    MetadataPointer {
+        authority: Option<&'a Address>,
+        metadata_address: Option<&'a Address>,
+    },
+}
+
+impl MintExtensionConfig<'_> {
+    pub const fn extension_type(&self) -> ExtensionType {
+        match self {
+            MintExtensionConfig::TransferFeeConfig { .. } => ExtensionType::TransferFeeConfig,
+            MintExtensionConfig::MintCloseAuthority { .. } => ExtensionType::MintCloseAuthority,
+            MintExtensionConfig::NonTransferable => ExtensionType::NonTransferable,
+            MintExtensionConfig::InterestBearingConfig { .. } => {
+                ExtensionType::InterestBearingConfig
+            }
+            MintExtensionConfig::PermanentDelegate { .. } => ExtensionType::PermanentDelegate,
+            MintExtensionConfig::MetadataPointer { .. } => ExtensionType::MetadataPointer,
+        }
+    }
+
+    /// Invokes the instruction initializing the extension on an uninitialized `mint`.
+    pub fn initialize(&self, mint: &AccountView, token_program: &Address) -> Result<(), Error> {
+        let mut data = [0; 78];
+        let len = match *self {
+            MintExtensionConfig::TransferFeeConfig {
+                transfer_fee_config_authority,
+                withdraw_withheld_authority,
+                transfer_fee_basis_points,
+                maximum_fee,
+            } => {
+                data[0] = 26;
+                let mut len = 2;
+                len += write_option(&mut data[len..], transfer_fee_config_authority);
+                len += write_option(&mut data[len..], withdraw_withheld_authority);
+                data[len..len + 2].copy_from_slice(&transfer_fee_basis_points.to_le_bytes());
+                data[len + 2..len + 10].copy_from_slice(&maximum_fee.to_le_bytes());
+                len + 10
+            }
+            MintExtensionConfig::MintCloseAuthority { close_authority } => {
+                data[0] = 25;
+                1 + write_option(&mut data[1..], close_authority)
+            }
+            MintExtensionConfig::NonTransferable => {
+                data[0] = 32;
+                1
+            }
+            MintExtensionConfig::InterestBearingConfig {
+                rate_authority,
+                rate,
+            } => {
+                data[0] = 33;
+                write_optional_address(&mut data[2..], rate_authority);
+                data[34..36].copy_from_slice(&rate.to_le_bytes());
+                36
+            }
+            MintExtensionConfig::PermanentDelegate { delegate } => {
+                data[0] = 35;
+                data[1..33].copy_from_slice(delegate.as_ref());
+                33
+            }
+            MintExtensionConfig::MetadataPointer {
+                authority,
+                metadata_address,
+            } => {
+                data[0] = 39;
+                write_optional_address(&mut data[2..], authority);
+                write_optional_address(&mut data[34..], metadata_address);
+                66
+            }
+        };
+
+        invoke(
+            token_program,
+            [InstructionAccount::writable(mint.address())],
+            [mint],
+            &data[..len],
+            None,
+        )
+    }
+}
@@ -0,0 +1,398 @@
+use {
+    super::TLV_HEADER_LEN,
+    crate::{invoke, token_program_id, Mint},
+    pinocchio::{
+        cpi::Signer as CpiSigner, error::ProgramError, instruction::InstructionAccount,
+        sysvars::rent::Rent,
+    },
+    solana_address::Address,
+    typhoon_accounts::{Account, Mut, ReadableAccount, WritableAccount},
+    typhoon_errors::{Error, ErrorCode},
+};
+
+/// Extension type of the token metadata stored in a Token-2022 mint.
+pub(crate) const TOKEN_METADATA_EXTENSION: u16 = 19;
+
+/// Size of the update authority and the mint before the strings.
+const FIXED_LEN: usize = 64;
+/// Size of the length prefix of strings and vectors.
+const LEN_PREFIX: usize = 4;
+/// Maximum size of the data of a metadata instruction, bounded by the transaction size.
+const MAX_INSTRUCTION_LEN: usize = 1232;
+
+const INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+const UPDATE_FIELD: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+const REMOVE_KEY: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+const UPDATE_AUTHORITY: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+
+/// Token metadata of a mint, read in place from its TLV entry.
+///
+/// The layout is validated by [`TokenMetadata::from_bytes`], so accessors don't fail.
+#[repr(transparent)]
+pub struct TokenMetadata([u8]);
+
+impl TokenMetadata {
+    /// Validates the borsh layout of the metadata and its UTF-8 strings.
+    pub fn from_bytes(data: &[u8]) -> Result<&TokenMetadata, ProgramError> {
+        let mut offset = FIXED_LEN;
+        for _ in 0..3 {
+            offset = read_str(data, offset)
+                .ok_or(ProgramError::InvalidAccountData)?
+                .1;
+        }
+
+        let count = read_len(data, offset).ok_or(ProgramError::InvalidAccountData)?;
+        offset += LEN_PREFIX;
+        for _ in 0..count {
+            for _ in 0..2 {
+                offset = read_str(data, offset)
+                    .ok_or(ProgramError::InvalidAccountData)?
+                    .1;
+            }
+        }
+
+        // SAFETY: `TokenMetadata` is `#[repr(transparent)]` over `[u8]`.
+        Ok(unsafe { &*(data as *const [u8] as *const TokenMetadata) })
+    }
+
+    /// Size added to a mint by initializing the metadata, including the TLV header.
+    pub const fn space(name: &str, symbol: &str, uri: &str) -> usize {
+        TLV_HEADER_LEN
+            + FIXED_LEN
+            + 3 * LEN_PREFIX
+            + name.len()
+            + symbol.len()
+            + uri.len()
+            + LEN_PREFIX
+    }
+
+    /// Size added by a new additional field.
+    pub const fn field_space(key: &str, value: &str) -> usize {
+        2 * LEN_PREFIX + key.len() + value.len()
+    }
+
+    #[inline(always)]
+    pub fn update_authority(&self) -> Option<&Address> {
+        let address = self.address_at(0);
+        (address.as_array() != &[0; 32]).then_some(address)
+    }
+
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        self.address_at(32)
+    }
+
+    #[inline(always)]
+    pub fn name(&self) -> &str {
+        self.str_at(FIXED_LEN).0
+    }
+
+    #[inline(always)]
+    pub fn symbol(&self) -> &str {
+        let (_, offset) = self.str_at(FIXED_LEN);
+        self.str_at(offset).0
+    }
+
+    #[inline(always)]
+    pub fn uri(&self) -> &str {
+        let (_, offset) = self.str_at(FIXED_LEN);
+        let (_, offset) = self.str_at(offset);
+        self.str_at(offset).0
+    }
+
+    /// Iterates over the additional `(key, value)` fields.
+    pub fn additional_metadata(&self) -> AdditionalMetadata<'_> {
+        let mut offset = FIXED_LEN;
+        for _ in 0..3 {
+            offset = self.str_at(offset).1;
+        }
+
+        AdditionalMetadata {
+            metadata: self,
+            remaining: read_len(&self.0, offset).unwrap_or_default(),
+            offset: offset + LEN_PREFIX,
+        }
+    }
+
+    /// Returns the value of the additional field `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.additional_metadata()
+            .find_map(|(field, value)| (field == key).then_some(value))
+    }
+
+    #[inline(always)]
+    fn address_at(&self, offset: usize) -> &Address {
+        // SAFETY: The data holds at least `FIXED_LEN` bytes and `Address` has an alignment of 1.
+        unsafe { &*(self.0.as_ptr().add(offset) as *const Address) }
+    }
+
+    #[inline(always)]
+    fn str_at(&self, offset: usize) -> (&str, usize) {
+        let len = read_len(&self.0, offset).unwrap_or_default() as usize;
+        let start = offset + LEN_PREFIX;
+        // SAFETY: Strings are checked to be in bounds and valid UTF-8 by `from_bytes`.
+        let value = unsafe { core::str::from_utf8_unchecked(&self.0[start..start + len]) };
+        (value, start + len)
+    }
+}
+
+/// Iterator over the additional fields of a [`TokenMetadata`].
+pub struct AdditionalMetadata<'a> {
+    metadata: &'a TokenMetadata,
+    remaining: u32,
+    offset: usize,
+}
+
+impl<'a> Iterator for AdditionalMetadata<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let (key, offset) = self.metadata.str_at(self.offset);
+        let (value, offset) = self.metadata.str_at(offset);
+        self.remaining -= 1;
+        self.offset = offset;
+        Some((key, value))
+    }
+}
+
+#[inline(always)]
+fn read_len(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + LEN_PREFIX)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[inline(always)]
+fn read_str(data: &[u8], offset: usize) -> Option<(&str, usize)> {
+    let start = offset + LEN_PREFIX;
+    let end = start.checked_add(read_len(data, offset)? as usize)?;
+    let value = core::str::from_utf8(data.get(start..end)?).ok()?;
+    Some((value, end))
+}
+
+/// Lamports to add to `account` for it to stay rent exempt with `extra_space` more bytes.
+#[inline]
+pub fn rent_for_extra_space(
+    account: &impl ReadableAccount,
+    extra_space: usize,
+    rent: &Rent,
+) -> Result<u64, ProgramError> {
+    let minimum_balance = rent.try_minimum_balance(account.as_ref().data_len() + extra_space)?;
+    Ok(minimum_balance.saturating_sub(account.lamports()))
+}
+
+/// Field of the token metadata.
+pub enum MetadataField<'a> {
+    Name,
+    Symbol,
+    Uri,
+    Key(&'a str),
+}
+
+/// Instruction data written in place, without allocating.
+struct InstructionData {
+    data: [u8; MAX_INSTRUCTION_LEN],
+    len: usize,
+}
+
+impl InstructionData {
+    #[inline(always)]
+    fn new(discriminator: [u8; 8]) -> Self {
+        let mut data = [0; MAX_INSTRUCTION_LEN];
+        data[..8].copy_from_slice(&discriminator);
+        InstructionData { data, len: 8 }
+    }
+
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let Some(dst) = self.data.get_mut(self.len..self.len + bytes.len()) else {
+            return Err(ErrorCode::BufferFull.into());
+        };
+        dst.copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn write_str(&mut self, value: &str) -> Result<(), Error> {
+        self.write(&(value.len() as u32).to_le_bytes())?;
+        self.write(value.as_bytes())
+    }
+
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+}
+
+/// Token metadata interface instructions on a Token-2022 mint holding its own metadata.
+///
+/// The mint needs a `MetadataPointer` to itself and enough lamports for the resized
+/// account, see [`TokenMetadata::space`] and [`rent_for_extra_space`]. The authorities sign
+/// the instructions, or PDAs of this program with the given `seeds`.
+pub trait TokenMetadataCpi: WritableAccount {
+    /// Initializes the metadata of the mint, signed by the mint authority.
+    #[inline]
+    fn initialize_metadata(
+        &self,
+        update_authority: &impl ReadableAccount,
+        mint_authority: &impl ReadableAccount,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = InstructionData::new(INITIALIZE);
+        data.write_str(name)?;
+        data.write_str(symbol)?;
+        data.write_str(uri)?;
+
+        invoke(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly(update_authority.address()),
+                InstructionAccount::readonly(self.address()),
+                InstructionAccount::readonly_signer(mint_authority.address()),
+            ],
+            [
+                self.as_ref(),
+                update_authority.as_ref(),
+                self.as_ref(),
+                mint_authority.as_ref(),
+            ],
+            data.as_slice(),
+            seeds,
+        )
+    }
+
+    /// Sets `field` to `value`, adding the field when it is a new key.
+    #[inline]
+    fn update_metadata_field(
+        &self,
+        update_authority: &impl ReadableAccount,
+        field: MetadataField<'_>,
+        value: &str,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = InstructionData::new(UPDATE_FIELD);
+        match field {
+            MetadataField::Name => data.write(&[0])?,
+            MetadataField::Symbol => data.write(&[1])?,
+            MetadataField::Uri => data.write(&[2])?,
+            MetadataField::Key(key) => {
+                data.write(&[3])?;
+                data.write_str(key)?;
+            }
+        }
+        data.write_str(value)?;
+
+        invoke(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly_signer(update_authority.address()),
+            ],
+            [self.as_ref(), update_authority.as_ref()],
+            data.as_slice(),
+            seeds,
+        )
+    }
+
+    /// Removes the additional field `key`, succeeding when it is missing if `idempotent`.
+    #[inline]
+    fn remove_metadata_key(
+        &self,
+        update_authority: &impl ReadableAccount,
+        key: &str,
+        idempotent: bool,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = InstructionData::new(REMOVE_KEY);
+        data.write(&[idempotent as u8])?;
+        data.write_str(key)?;
+
+        invoke(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly_signer(update_authority.address()),
+            ],
+            [self.as_ref(), update_authority.as_ref()],
+            data.as_slice(),
+            seeds,
+        )
+    }
+
+    /// Sets the update authority, making the metadata immutable with `None`.
+    #[inline]
+    fn update_metadata_authority(
+        &self,
+        update_authority: &impl ReadableAccount,
+        new_authority: Option<&Address>,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = [0; 40];
+        data[..8].copy_from_slice(&UPDATE_AUTHORITY);
+        if let Some(new_authority) = new_authority {
+            data[8..].copy_from_slice(new_authority.as_ref());
+        }
+
+        invoke(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly_signer(update_authority.address()),
+            ],
+            [self.as_ref(), update_authority.as_ref()],
+            &data,
+            seeds,
+        )
+    }
+}
+
+impl TokenMetadataCpi for Mut<Account<'_, Mint>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_str(data: &mut [u8], offset: usize, value: &str) -> usize {
+        data[offset..offset + 4].copy_from_slice(&(value.len() as u32).to_le_bytes());
+        data[offset + 4..offset + 4 + value.len()].copy_from_slice(value.as_bytes());
+        offset + 4 + value.len()
+    }
+
+    #[test]
+    fn test_token_metadata() {
+        let mut data = [0; 128];
+        data[32..64].fill(1);
+        let mut offset = write_str(&mut data, FIXED_LEN, "Typhoon");
+        offset = write_str(&mut data, offset, "TYP");
+        offset = write_str(&mut data, offset, "https://typhoon.rs");
+        data[offset..offset + 4].copy_from_slice(&1u32.to_le_bytes());
+        offset = write_str(&mut data, offset + 4, "edition");
+        offset = write_str(&mut data, offset, "1");
+
+        let metadata = TokenMetadata::from_bytes(&data[..offset]).unwrap();
+        assert_eq!(metadata.update_authority(), None);
+        assert_eq!(metadata.mint(), &Address::new_from_array([1; 32]));
+        assert_eq!(metadata.name(), "Typhoon");
+        assert_eq!(metadata.symbol(), "TYP");
+        assert_eq!(metadata.uri(), "https://typhoon.rs");
+        assert_eq!(metadata.get("edition"), Some("1"));
+        assert_eq!(metadata.get("rarity"), None);
+        assert_eq!(
+            TokenMetadata::space("Typhoon", "TYP", "https://typhoon.rs")
+                + TokenMetadata::field_space("edition", "1"),
+            TLV_HEADER_LEN + offset
+        );
+
+        assert!(TokenMetadata::from_bytes(&data[..offset - 1]).is_err());
+        data[FIXED_LEN + 4] = 0xff;
+        assert!(TokenMetadata::from_bytes(&data[..offset]).is_err());
+    }
+}
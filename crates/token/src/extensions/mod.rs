@@ -0,0 +1,354 @@
+use {
+    crate::{Mint, TokenAccount},
+    pinocchio::{account::Ref, error::ProgramError},
+    typhoon_accounts::AccountData,
+};
+
+mod init;
+mod metadata;
+mod state;
+mod transfer_fee;
+
+pub use {init::*, metadata::*, state::*, transfer_fee::*};
+
+/// Offset of the account type byte, the mint base state being padded to the token account size.
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+/// Offset of the first extension entry.
+const TLV_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+/// Size of the type and length header of an extension entry.
+const TLV_HEADER_LEN: usize = 4;
+/// Size of a multisig account, which extended accounts must not collide with.
+const MULTISIG_LEN: usize = 355;
+
+/// Token-2022 extension identifiers.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtensionType {
+    TransferFeeConfig = 1,
+    TransferFeeAmount = 2,
+    MintCloseAuthority = 3,
+    ImmutableOwner = 7,
+    NonTransferable = 9,
+    InterestBearingConfig = 10,
+    PermanentDelegate = 12,
+    NonTransferableAccount = 13,
+    MetadataPointer = 18,
+}
+
+impl ExtensionType {
+    /// Size of the extension value, without its header.
+    pub const fn len(self) -> usize {
+        match self {
+            ExtensionType::TransferFeeConfig => size_of::<TransferFeeConfig>(),
+            ExtensionType::TransferFeeAmount => size_of::<TransferFeeAmount>(),
+            ExtensionType::MintCloseAuthority => size_of::<MintCloseAuthority>(),
+            ExtensionType::ImmutableOwner => size_of::<ImmutableOwner>(),
+            ExtensionType::NonTransferable => size_of::<NonTransferable>(),
+            ExtensionType::InterestBearingConfig => size_of::<InterestBearingConfig>(),
+            ExtensionType::PermanentDelegate => size_of::<PermanentDelegate>(),
+            ExtensionType::NonTransferableAccount => size_of::<NonTransferableAccount>(),
+            ExtensionType::MetadataPointer => size_of::<MetadataPointer>(),
+        }
+    }
+
+    /// Returns `true` when the extension has no value.
+    pub const fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Base state that Token-2022 extends with extensions.
+pub trait BaseState {
+    /// Size of the state without extensions.
+    const BASE_LEN: usize;
+    /// Account type byte written before the extensions.
+    const ACCOUNT_TYPE: u8;
+
+    /// Size of an account holding the given extensions.
+    #[inline]
+    fn space_with_extensions(extensions: &[ExtensionType]) -> usize {
+        space(
+            Self::BASE_LEN,
+            extensions.iter().map(|extension| extension.len()),
+        )
+    }
+}
+
+impl BaseState for Mint {
+    const BASE_LEN: usize = Mint::LEN;
+    const ACCOUNT_TYPE: u8 = 1;
+}
+
+impl BaseState for TokenAccount {
+    const BASE_LEN: usize = TokenAccount::LEN;
+    const ACCOUNT_TYPE: u8 = 2;
+}
+
+/// Size of an account holding extensions of the given value sizes.
+#[inline]
+pub(crate) fn space(base_len: usize, extensions: impl Iterator<Item = usize>) -> usize {
+    let mut tlv_len = 0;
+    let mut is_empty = true;
+    for len in extensions {
+        tlv_len += TLV_HEADER_LEN + len;
+        is_empty = false;
+    }
+
+    if is_empty {
+        return base_len;
+    }
+
+    match TLV_OFFSET + tlv_len {
+        // An extra empty entry header keeps the account from being read as a multisig.
+        MULTISIG_LEN => MULTISIG_LEN + size_of::<ExtensionType>(),
+        len => len,
+    }
+}
+
+/// Size of a token account of the mint with `mint_data`, holding the extensions that
+/// `InitializeAccount` adds for the extensions of the mint.
+///
+/// Fails with `InvalidAccountData` if the mint holds an extension not listed in
+/// `ExtensionType` or token metadata, e.g. a transfer hook, whose account extensions would be
+/// missing.
+pub fn token_account_space(mint_data: &[u8]) -> Result<usize, ProgramError> {
+    // The other extensions of this list and the token metadata add nothing to the token
+    // accounts.
+    const SUPPORTED: [ExtensionType; 6] = [
+        ExtensionType::TransferFeeConfig,
+        ExtensionType::NonTransferable,
+        ExtensionType::MintCloseAuthority,
+        ExtensionType::InterestBearingConfig,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MetadataPointer,
+    ];
+
+    let mut has_transfer_fee = false;
+    let mut is_non_transferable = false;
+    let unsupported = find_extension::<Mint>(mint_data, |extension_type| {
+        let is = |extension: ExtensionType| extension as u16 == extension_type;
+        has_transfer_fee |= is(ExtensionType::TransferFeeConfig);
+        is_non_transferable |= is(ExtensionType::NonTransferable);
+        extension_type != TOKEN_METADATA_EXTENSION && !SUPPORTED.into_iter().any(is)
+    })?;
+    if unsupported.is_some() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let required = [
+        (has_transfer_fee, ExtensionType::TransferFeeAmount),
+        (is_non_transferable, ExtensionType::NonTransferableAccount),
+        (is_non_transferable, ExtensionType::ImmutableOwner),
+    ];
+    Ok(space(
+        TokenAccount::LEN,
+        required
+            .into_iter()
+            .filter(|(is_required, _)| *is_required)
+            .map(|(_, extension)| extension.len()),
+    ))
+}
+
+/// Extension value stored in the TLV region of a Token-2022 account.
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` with an alignment of 1, without padding, and valid for
+/// any bit pattern, since it is read in place from the account data.
+pub unsafe trait Extension {
+    /// The state extended by this extension.
+    type Base: BaseState;
+    /// The extension identifier.
+    const TYPE: ExtensionType;
+}
+
+/// Finds the extension `E` in the data of a Token-2022 account.
+///
+/// Returns `None` when the account has no extensions or doesn't hold `E`.
+pub fn get_extension<E: Extension>(data: &[u8]) -> Result<Option<&E>, ProgramError> {
+    let Some(value) = get_extension_bytes::<E::Base>(data, E::TYPE as u16)? else {
+        return Ok(None);
+    };
+
+    if value.len() != size_of::<E>() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // SAFETY: `E` has an alignment of 1, is valid for any bit pattern and `value`
+    // holds exactly `size_of::<E>()` bytes.
+    Ok(Some(unsafe { &*(value.as_ptr() as *const E) }))
+}
+
+/// Finds the value of the extension `extension_type` in the data of a Token-2022 account
+/// extending `B`, for extensions of variable size.
+pub fn get_extension_bytes<B: BaseState>(
+    data: &[u8],
+    extension_type: u16,
+) -> Result<Option<&[u8]>, ProgramError> {
+    find_extension::<B>(data, |current_type| current_type == extension_type)
+}
+
+/// Walks the extension entries of `data`, returning the value of the first one whose type
+/// matches `predicate`.
+fn find_extension<B: BaseState>(
+    data: &[u8],
+    mut predicate: impl FnMut(u16) -> bool,
+) -> Result<Option<&[u8]>, ProgramError> {
+    if data.len() == B::BASE_LEN {
+        return Ok(None);
+    }
+
+    if data.len() < TLV_OFFSET || data[ACCOUNT_TYPE_OFFSET] != B::ACCOUNT_TYPE {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut offset = TLV_OFFSET;
+    while let Some(header) = data.get(offset..offset + TLV_HEADER_LEN) {
+        let current_type = u16::from_le_bytes([header[0], header[1]]);
+        if current_type == 0 {
+            break;
+        }
+
+        let start = offset + TLV_HEADER_LEN;
+        let end = start + u16::from_le_bytes([header[2], header[3]]) as usize;
+        let Some(value) = data.get(start..end) else {
+            return Err(ProgramError::InvalidAccountData);
+        };
+
+        if predicate(current_type) {
+            return Ok(Some(value));
+        }
+
+        offset = end;
+    }
+
+    Ok(None)
+}
+
+/// Reads the Token-2022 extensions of mints and token accounts.
+pub trait TokenExtensions: AccountData {
+    /// Returns the extension `E` of the account, or `None` when it doesn't hold it.
+    #[inline]
+    fn extension<E>(&self) -> Result<Option<Ref<'_, E>>, ProgramError>
+    where
+        E: Extension<Base = Self::Data>,
+    {
+        let data = self.as_ref().try_borrow()?;
+        match Ref::try_map(data, |data| match get_extension::<E>(data) {
+            Ok(Some(extension)) => Ok(extension),
+            Ok(None) => Err(None),
+            Err(err) => Err(Some(err)),
+        }) {
+            Ok(extension) => Ok(Some(extension)),
+            Err((_, None)) => Ok(None),
+            Err((_, Some(err))) => Err(err),
+        }
+    }
+
+    /// Returns the token metadata stored in the mint, or `None` when it has none.
+    #[inline]
+    fn token_metadata(&self) -> Result<Option<Ref<'_, TokenMetadata>>, ProgramError>
+    where
+        Self: AccountData<Data = Mint>,
+    {
+        let data = self.as_ref().try_borrow()?;
+        match Ref::try_map(data, |data| {
+            match get_extension_bytes::<Mint>(data, TOKEN_METADATA_EXTENSION) {
+                Ok(Some(value)) => TokenMetadata::from_bytes(value).map_err(Some),
+                Ok(None) => Err(None),
+                Err(err) => Err(Some(err)),
+            }
+        }) {
+            Ok(metadata) => Ok(Some(metadata)),
+            Err((_, None)) => Ok(None),
+            Err((_, Some(err))) => Err(err),
+        }
+    }
+}
+
+impl<T> TokenExtensions for T where T: AccountData {}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_address::Address};
+
+    #[test]
+    fn test_get_extension() {
+        let space = Mint::space_with_extensions(&[
+            ExtensionType::PermanentDelegate,
+            ExtensionType::NonTransferable,
+        ]);
+        assert_eq!(space, TLV_OFFSET + 4 + 32 + 4);
+
+        let mut data = [0; TLV_OFFSET + 4 + 32 + 4];
+        data[ACCOUNT_TYPE_OFFSET] = Mint::ACCOUNT_TYPE;
+        data[TLV_OFFSET..TLV_OFFSET + 4].copy_from_slice(&[12, 0, 32, 0]);
+        data[TLV_OFFSET + 4..TLV_OFFSET + 36].fill(7);
+        data[TLV_OFFSET + 36..].copy_from_slice(&[9, 0, 0, 0]);
+
+        let delegate = get_extension::<PermanentDelegate>(&data).unwrap().unwrap();
+        assert_eq!(delegate.delegate(), Some(&Address::new_from_array([7; 32])));
+        assert!(get_extension::<NonTransferable>(&data).unwrap().is_some());
+        assert!(get_extension::<MetadataPointer>(&data).unwrap().is_none());
+        assert!(get_extension::<MetadataPointer>(&data[..Mint::LEN])
+            .unwrap()
+            .is_none());
+
+        data[ACCOUNT_TYPE_OFFSET] = TokenAccount::ACCOUNT_TYPE;
+        assert!(matches!(
+            get_extension::<PermanentDelegate>(&data),
+            Err(ProgramError::InvalidAccountData)
+        ));
+        assert!(get_extension::<ImmutableOwner>(&data[..TLV_OFFSET + 2])
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_token_account_space() {
+        assert_eq!(token_account_space(&[0; Mint::LEN]), Ok(TokenAccount::LEN));
+
+        let mut data = [0; TLV_OFFSET + 4 + size_of::<TransferFeeConfig>() + 4];
+        data[ACCOUNT_TYPE_OFFSET] = Mint::ACCOUNT_TYPE;
+        data[TLV_OFFSET..TLV_OFFSET + 4].copy_from_slice(&[1, 0, 108, 0]);
+        assert_eq!(
+            token_account_space(&data[..TLV_OFFSET + 112]),
+            Ok(TokenAccount::space_with_extensions(&[
+                ExtensionType::TransferFeeAmount
+            ]))
+        );
+        assert_eq!(token_account_space(&data[..TLV_OFFSET + 112]), Ok(178));
+
+        data[TLV_OFFSET + 112..].copy_from_slice(&[9, 0, 0, 0]);
+        assert_eq!(token_account_space(&data), Ok(186));
+
+        data[TLV_OFFSET..TLV_OFFSET + 4].copy_from_slice(&[1, 0, 200, 0]);
+        assert_eq!(
+            token_account_space(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // A mint close authority adds nothing, a transfer hook isn't supported.
+        let mut data = [0; TLV_OFFSET + 4 + size_of::<MintCloseAuthority>() + 4 + 64];
+        data[ACCOUNT_TYPE_OFFSET] = Mint::ACCOUNT_TYPE;
+        data[TLV_OFFSET..TLV_OFFSET + 4].copy_from_slice(&[3, 0, 32, 0]);
+        assert_eq!(
+            token_account_space(&data[..TLV_OFFSET + 36]),
+            Ok(TokenAccount::LEN)
+        );
+        data[TLV_OFFSET + 36..TLV_OFFSET + 40].copy_from_slice(&[14, 0, 64, 0]);
+        assert_eq!(
+            token_account_space(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_space_avoids_multisig_len() {
+        assert_eq!(
+            space(Mint::LEN, [MULTISIG_LEN - TLV_OFFSET - 4].into_iter()),
+            357
+        );
+        assert_eq!(space(Mint::LEN, core::iter::empty()), Mint::LEN);
+    }
+}
@@ -0,0 +1,241 @@
+use {
+    super::{Extension, ExtensionType},
+    crate::{Mint, TokenAccount},
+    solana_address::Address,
+};
+
+/// Reads an optional address stored as zeros when unset.
+#[inline(always)]
+fn optional_address(address: &Address) -> Option<&Address> {
+    (address.as_array() != &[0; 32]).then_some(address)
+}
+
+/// Transfer fee applied from an epoch.
+#[repr(C)]
+pub struct TransferFee {
+    epoch: [u8; 8],
+    maximum_fee: [u8; 8],
+    transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    /// First epoch where the fee applies.
+    #[inline(always)]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    /// Maximum fee charged on a transfer, in token base units.
+    #[inline(always)]
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    /// Fee charged on a transfer, in basis points of the amount.
+    #[inline(always)]
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+}
+
+/// Transfer fee configuration of a mint.
+#[repr(C)]
+pub struct TransferFeeConfig {
+    transfer_fee_config_authority: Address,
+    withdraw_withheld_authority: Address,
+    withheld_amount: [u8; 8],
+    older_transfer_fee: TransferFee,
+    newer_transfer_fee: TransferFee,
+}
+
+impl TransferFeeConfig {
+    #[inline(always)]
+    pub fn transfer_fee_config_authority(&self) -> Option<&Address> {
+        optional_address(&self.transfer_fee_config_authority)
+    }
+
+    #[inline(always)]
+    pub fn withdraw_withheld_authority(&self) -> Option<&Address> {
+        optional_address(&self.withdraw_withheld_authority)
+    }
+
+    /// Fees withheld on the mint, harvested from token accounts.
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    #[inline(always)]
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    #[inline(always)]
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// Returns the transfer fee applying at `epoch`.
+    #[inline(always)]
+    pub fn epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+}
+
+unsafe impl Extension for TransferFeeConfig {
+    type Base = Mint;
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+/// Transfer fees withheld on a token account.
+#[repr(C)]
+pub struct TransferFeeAmount {
+    withheld_amount: [u8; 8],
+}
+
+impl TransferFeeAmount {
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+unsafe impl Extension for TransferFeeAmount {
+    type Base = TokenAccount;
+    const TYPE: ExtensionType = ExtensionType::TransferFeeAmount;
+}
+
+/// Authority allowed to close a mint with no supply.
+#[repr(C)]
+pub struct MintCloseAuthority {
+    close_authority: Address,
+}
+
+impl MintCloseAuthority {
+    #[inline(always)]
+    pub fn close_authority(&self) -> Option<&Address> {
+        optional_address(&self.close_authority)
+    }
+}
+
+unsafe impl Extension for MintCloseAuthority {
+    type Base = Mint;
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
+
+/// Marks a token account whose owner cannot be changed.
+#[repr(C)]
+pub struct ImmutableOwner;
+
+unsafe impl Extension for ImmutableOwner {
+    type Base = TokenAccount;
+    const TYPE: ExtensionType = ExtensionType::ImmutableOwner;
+}
+
+/// Marks a mint whose tokens cannot be transferred.
+#[repr(C)]
+pub struct NonTransferable;
+
+unsafe impl Extension for NonTransferable {
+    type Base = Mint;
+    const TYPE: ExtensionType = ExtensionType::NonTransferable;
+}
+
+/// Marks a token account of a `NonTransferable` mint.
+#[repr(C)]
+pub struct NonTransferableAccount;
+
+unsafe impl Extension for NonTransferableAccount {
+    type Base = TokenAccount;
+    const TYPE: ExtensionType = ExtensionType::NonTransferableAccount;
+}
+
+/// Interest accruing on the displayed amounts of a mint.
+#[repr(C)]
+pub struct InterestBearingConfig {
+    rate_authority: Address,
+    initialization_timestamp: [u8; 8],
+    pre_update_average_rate: [u8; 2],
+    last_update_timestamp: [u8; 8],
+    current_rate: [u8; 2],
+}
+
+impl InterestBearingConfig {
+    #[inline(always)]
+    pub fn rate_authority(&self) -> Option<&Address> {
+        optional_address(&self.rate_authority)
+    }
+
+    #[inline(always)]
+    pub fn initialization_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.initialization_timestamp)
+    }
+
+    /// Average rate in basis points before the last update.
+    #[inline(always)]
+    pub fn pre_update_average_rate(&self) -> i16 {
+        i16::from_le_bytes(self.pre_update_average_rate)
+    }
+
+    #[inline(always)]
+    pub fn last_update_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.last_update_timestamp)
+    }
+
+    /// Current rate in basis points.
+    #[inline(always)]
+    pub fn current_rate(&self) -> i16 {
+        i16::from_le_bytes(self.current_rate)
+    }
+}
+
+unsafe impl Extension for InterestBearingConfig {
+    type Base = Mint;
+    const TYPE: ExtensionType = ExtensionType::InterestBearingConfig;
+}
+
+/// Delegate allowed to transfer or burn tokens from any account of a mint.
+#[repr(C)]
+pub struct PermanentDelegate {
+    delegate: Address,
+}
+
+impl PermanentDelegate {
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<&Address> {
+        optional_address(&self.delegate)
+    }
+}
+
+unsafe impl Extension for PermanentDelegate {
+    type Base = Mint;
+    const TYPE: ExtensionType = ExtensionType::PermanentDelegate;
+}
+
+/// Address of the account holding the metadata of a mint.
+#[repr(C)]
+pub struct MetadataPointer {
+    authority: Address,
+    metadata_address: Address,
+}
+
+impl MetadataPointer {
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    #[inline(always)]
+    pub fn metadata_address(&self) -> Option<&Address> {
+        optional_address(&self.metadata_address)
+    }
+}
+
+unsafe impl Extension for MetadataPointer {
+    type Base = Mint;
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+}
@@ -0,0 +1,133 @@
+use {
+    super::{TokenExtensions, TransferFee, TransferFeeConfig},
+    crate::Mint,
+    pinocchio::{
+        error::ProgramError,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    typhoon_accounts::AccountData,
+};
+
+/// Basis points in a whole amount.
+const ONE_IN_BASIS_POINTS: u128 = 10_000;
+
+#[inline(always)]
+fn ceil_div(numerator: u128, denominator: u128) -> Option<u128> {
+    numerator
+        .checked_add(denominator)?
+        .checked_sub(1)?
+        .checked_div(denominator)
+}
+
+impl TransferFee {
+    /// Fee withheld on a transfer of `pre_fee_amount`, rounded up and capped to the maximum fee.
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let basis_points = self.transfer_fee_basis_points() as u128;
+        if basis_points == 0 || pre_fee_amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = (pre_fee_amount as u128).checked_mul(basis_points)?;
+        let fee = u64::try_from(ceil_div(numerator, ONE_IN_BASIS_POINTS)?).ok()?;
+        Some(fee.min(self.maximum_fee()))
+    }
+
+    /// Amount received on a transfer of `pre_fee_amount`.
+    pub fn calculate_post_fee_amount(&self, pre_fee_amount: u64) -> Option<u64> {
+        pre_fee_amount.checked_sub(self.calculate_fee(pre_fee_amount)?)
+    }
+
+    /// Amount to transfer for `post_fee_amount` to be received.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let maximum_fee = self.maximum_fee();
+        match (self.transfer_fee_basis_points() as u128, post_fee_amount) {
+            (0, _) => Some(post_fee_amount),
+            (_, 0) => Some(0),
+            (ONE_IN_BASIS_POINTS, _) => maximum_fee.checked_add(post_fee_amount),
+            (basis_points, _) => {
+                let numerator = (post_fee_amount as u128).checked_mul(ONE_IN_BASIS_POINTS)?;
+                let denominator = ONE_IN_BASIS_POINTS.checked_sub(basis_points)?;
+                let pre_fee_amount = ceil_div(numerator, denominator)?;
+
+                if pre_fee_amount.checked_sub(post_fee_amount as u128)? >= maximum_fee as u128 {
+                    post_fee_amount.checked_add(maximum_fee)
+                } else {
+                    u64::try_from(pre_fee_amount).ok()
+                }
+            }
+        }
+    }
+}
+
+impl TransferFeeConfig {
+    /// Fee withheld on a transfer of `pre_fee_amount` at `epoch`.
+    #[inline]
+    pub fn calculate_epoch_fee(&self, epoch: u64, pre_fee_amount: u64) -> Option<u64> {
+        self.epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+}
+
+/// Fee withheld on a transfer of `amount` tokens of `mint` in the current epoch.
+///
+/// The fee is zero when the mint has no `TransferFeeConfig`.
+pub fn transfer_fee<A>(mint: &A, amount: u64) -> Result<u64, ProgramError>
+where
+    A: AccountData<Data = Mint>,
+{
+    let Some(config) = mint.extension::<TransferFeeConfig>()? else {
+        return Ok(0);
+    };
+
+    config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// Amount credited to the destination of a transfer of `amount` tokens of `mint` in the
+/// current epoch, e.g. what an escrow actually holds after a deposit.
+#[inline]
+pub fn received_amount<A>(mint: &A, amount: u64) -> Result<u64, ProgramError>
+where
+    A: AccountData<Data = Mint>,
+{
+    amount
+        .checked_sub(transfer_fee(mint, amount)?)
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_fee(basis_points: u16, maximum_fee: u64) -> TransferFee {
+        let mut data = [0; 18];
+        data[8..16].copy_from_slice(&maximum_fee.to_le_bytes());
+        data[16..].copy_from_slice(&basis_points.to_le_bytes());
+        // SAFETY: `TransferFee` is an alignment 1 type of 18 bytes.
+        unsafe { core::mem::transmute(data) }
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        let fee = new_fee(50, 3_000);
+        assert_eq!(fee.calculate_fee(0), Some(0));
+        assert_eq!(fee.calculate_fee(1), Some(1));
+        assert_eq!(fee.calculate_fee(10_000), Some(50));
+        assert_eq!(fee.calculate_fee(10_001), Some(51));
+        assert_eq!(fee.calculate_fee(1_000_000_000), Some(3_000));
+        assert_eq!(fee.calculate_post_fee_amount(10_000), Some(9_950));
+
+        for post_fee_amount in [0, 1, 9_950, 9_951, 999_997_000] {
+            let pre_fee_amount = fee.calculate_pre_fee_amount(post_fee_amount).unwrap();
+            assert_eq!(
+                fee.calculate_post_fee_amount(pre_fee_amount),
+                Some(post_fee_amount)
+            );
+        }
+
+        let fee = new_fee(10_000, 5);
+        assert_eq!(fee.calculate_fee(100), Some(5));
+        assert_eq!(fee.calculate_pre_fee_amount(95), Some(100));
+        assert_eq!(new_fee(0, 5).calculate_fee(100), Some(0));
+    }
+}
@@ -1,21 +1,41 @@
 #![no_std]
 
 use {
-    core::{mem::transmute, ops::Deref},
-    pinocchio::error::ProgramError,
+    core::{
+        mem::{transmute, MaybeUninit},
+        ops::Deref,
+        slice::from_raw_parts,
+    },
+    pinocchio::{
+        cpi::{invoke_signed, invoke_signed_with_bounds, Signer as CpiSigner},
+        error::ProgramError,
+        hint::unlikely,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView,
+    },
     pinocchio_associated_token_account::ID as ATA_PROGRAM_ID,
     pinocchio_token::{
-        state::{Mint as SplMint, TokenAccount as SplTokenAccount},
+        instructions::MAX_MULTISIG_SIGNERS,
+        state::{Mint as SplMint, Multisig as SplMultisig, TokenAccount as SplTokenAccount},
         ID as TOKEN_PROGRAM_ID,
     },
     solana_address::{address_eq, Address},
+    typhoon_errors::Error,
     typhoon_traits::{Accessor, CheckOwner, CheckProgramId, DataStrategy, Discriminator},
 };
 
+#[cfg(feature = "token2022")]
+mod extensions;
+mod multisig;
+mod native;
+#[cfg(test)]
+mod test_utils;
 mod traits;
 
+#[cfg(feature = "token2022")]
+pub use extensions::*;
 pub use {
-    pinocchio_associated_token_account::instructions as ata_instructions,
+    multisig::*, native::*, pinocchio_associated_token_account::instructions as ata_instructions,
     pinocchio_token::instructions as spl_instructions, traits::*,
 };
 
@@ -23,6 +43,112 @@ pub use {
 const TOKEN_2022_PROGRAM_ID: Address =
     Address::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
+/// Returns the token program owning `account`.
+#[inline(always)]
+pub fn token_program_id(account: &AccountView) -> &'static Address {
+    #[cfg(feature = "token2022")]
+    {
+        if account.owned_by(&TOKEN_2022_PROGRAM_ID) {
+            &TOKEN_2022_PROGRAM_ID
+        } else {
+            &TOKEN_PROGRAM_ID
+        }
+    }
+    #[cfg(not(feature = "token2022"))]
+    {
+        let _ = account;
+        &TOKEN_PROGRAM_ID
+    }
+}
+
+/// Invokes an instruction of `program_id`, signed with `seeds` for PDAs.
+#[inline(always)]
+pub(crate) fn invoke<const ACCOUNTS: usize>(
+    program_id: &Address,
+    accounts: [InstructionAccount; ACCOUNTS],
+    views: [&AccountView; ACCOUNTS],
+    data: &[u8],
+    seeds: Option<&[CpiSigner]>,
+) -> Result<(), Error> {
+    let instruction = InstructionView {
+        program_id,
+        accounts: &accounts,
+        data,
+    };
+
+    invoke_signed(&instruction, &views, seeds.unwrap_or_default()).map_err(Into::into)
+}
+
+/// Maximum number of accounts of a token instruction followed by multisig signers.
+const MAX_ACCOUNTS_WITH_SIGNERS: usize = 4 + MAX_MULTISIG_SIGNERS;
+
+/// Invokes an instruction of `program_id` with the `signers` of a multisig appended to its
+/// accounts, as signers of the instruction when `is_signer` is set.
+#[inline(always)]
+pub(crate) fn invoke_with_signers<const ACCOUNTS: usize>(
+    program_id: &Address,
+    accounts: [InstructionAccount; ACCOUNTS],
+    views: [&AccountView; ACCOUNTS],
+    signers: &[&AccountView],
+    is_signer: bool,
+    data: &[u8],
+    seeds: Option<&[CpiSigner]>,
+) -> Result<(), Error> {
+    if signers.is_empty() {
+        return invoke(program_id, accounts, views, data, seeds);
+    }
+
+    let len = ACCOUNTS + signers.len();
+    if unlikely(len > MAX_ACCOUNTS_WITH_SIGNERS) {
+        return Err(ProgramError::InvalidArgument.into());
+    }
+
+    const UNINIT_ACCOUNT: MaybeUninit<InstructionAccount> = MaybeUninit::uninit();
+    const UNINIT_VIEW: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+    let mut instruction_accounts = [UNINIT_ACCOUNT; MAX_ACCOUNTS_WITH_SIGNERS];
+    let mut instruction_views = [UNINIT_VIEW; MAX_ACCOUNTS_WITH_SIGNERS];
+
+    for ((slot, view_slot), (account, view)) in instruction_accounts
+        .iter_mut()
+        .zip(instruction_views.iter_mut())
+        .zip(accounts.into_iter().zip(views))
+    {
+        slot.write(account);
+        view_slot.write(view);
+    }
+    for ((slot, view_slot), signer) in instruction_accounts[ACCOUNTS..]
+        .iter_mut()
+        .zip(instruction_views[ACCOUNTS..].iter_mut())
+        .zip(signers)
+    {
+        slot.write(InstructionAccount::new(signer.address(), false, is_signer));
+        view_slot.write(signer);
+    }
+
+    // SAFETY: The first `len` entries of both arrays are initialized above.
+    let (accounts, views) = unsafe {
+        (
+            from_raw_parts(
+                instruction_accounts.as_ptr() as *const InstructionAccount,
+                len,
+            ),
+            from_raw_parts(instruction_views.as_ptr() as *const &AccountView, len),
+        )
+    };
+    let instruction = InstructionView {
+        program_id,
+        accounts,
+        data,
+    };
+
+    invoke_signed_with_bounds::<MAX_ACCOUNTS_WITH_SIGNERS>(
+        &instruction,
+        views,
+        seeds.unwrap_or_default(),
+    )
+    .map_err(Into::into)
+}
+
 pub struct AtaTokenProgram;
 
 impl CheckProgramId for AtaTokenProgram {
@@ -53,6 +179,7 @@ pub struct SplStrategy;
 
 impl<'a> Accessor<'a, Mint> for SplStrategy {
     type Data = &'a Mint;
+    type Read = &'a Mint;
 
     #[inline(always)]
     fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
@@ -63,17 +190,18 @@ impl<'a> Accessor<'a, Mint> for SplStrategy {
     }
 
     #[inline(always)]
-    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError> {
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
         let Some((to_read, rem)) = data.split_at_checked(Mint::LEN) else {
             return Err(ProgramError::InvalidInstructionData);
         };
         *data = rem;
-        Ok(<Self as Accessor<Mint>>::access(to_read)?)
+        <Self as Accessor<Mint>>::access(to_read)
     }
 }
 
 impl<'a> Accessor<'a, TokenAccount> for SplStrategy {
     type Data = &'a TokenAccount;
+    type Read = &'a TokenAccount;
 
     #[inline(always)]
     fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
@@ -88,12 +216,47 @@ impl<'a> Accessor<'a, TokenAccount> for SplStrategy {
     }
 
     #[inline(always)]
-    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError> {
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
         let Some((to_read, rem)) = data.split_at_checked(TokenAccount::LEN) else {
             return Err(ProgramError::InvalidInstructionData);
         };
         *data = rem;
-        Ok(<Self as Accessor<TokenAccount>>::access(to_read)?)
+        <Self as Accessor<TokenAccount>>::access(to_read)
+    }
+}
+
+impl<'a> Accessor<'a, TokenMultisig> for SplStrategy {
+    type Data = &'a TokenMultisig;
+    type Read = &'a TokenMultisig;
+
+    #[inline(always)]
+    fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
+        if unlikely(data.len() < TokenMultisig::LEN) {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        // SAFETY: `TokenMultisig` is `#[repr(transparent)]` over `SplMultisig`,
+        // so the reference cast preserves layout/alignment/lifetime, and `data`
+        // holds at least `TokenMultisig::LEN` bytes.
+        let multisig = unsafe {
+            transmute::<&SplMultisig, &TokenMultisig>(SplMultisig::from_bytes_unchecked(data))
+        };
+
+        // Token accounts with extensions can be larger, a multisig has exactly this size.
+        if unlikely(data.len() != TokenMultisig::LEN || !multisig.is_initialized()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(multisig)
+    }
+
+    #[inline(always)]
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
+        let Some((to_read, rem)) = data.split_at_checked(TokenMultisig::LEN) else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        *data = rem;
+        <Self as Accessor<TokenMultisig>>::access(to_read)
     }
 }
 
@@ -173,10 +336,111 @@ impl Deref for TokenAccount {
     }
 }
 
-pub fn find_associated_token_address(mint: &Address, owner: &Address) -> Address {
+/// Multisig account usable as the authority of mints and token accounts.
+#[repr(transparent)]
+pub struct TokenMultisig(SplMultisig);
+
+impl TokenMultisig {
+    pub const LEN: usize = SplMultisig::LEN;
+}
+
+impl DataStrategy for TokenMultisig {
+    type Strategy = SplStrategy;
+}
+
+impl Discriminator for TokenMultisig {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+impl CheckOwner for TokenMultisig {
+    #[inline(always)]
+    fn owned_by(program_id: &Address) -> bool {
+        #[cfg(feature = "token2022")]
+        {
+            address_eq(program_id, &TOKEN_PROGRAM_ID)
+                || address_eq(program_id, &TOKEN_2022_PROGRAM_ID)
+        }
+        #[cfg(not(feature = "token2022"))]
+        {
+            address_eq(program_id, &TOKEN_PROGRAM_ID)
+        }
+    }
+}
+
+impl Deref for TokenMultisig {
+    type Target = SplMultisig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Derives the associated token account of `owner` for a `mint` of `token_program`.
+pub fn find_associated_token_address(
+    mint: &Address,
+    owner: &Address,
+    token_program: &Address,
+) -> Address {
     Address::find_program_address(
-        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
         &ATA_PROGRAM_ID,
     )
     .0
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::test_utils::{account_memory, view},
+        typhoon_accounts::{Account, FromAccountInfo, ReadableAccountData},
+    };
+
+    fn multisig_data(account: &Account<TokenMultisig>) -> Result<(u8, usize), ProgramError> {
+        let multisig = account.data()?;
+        Ok((multisig.required_signers(), multisig.signers_len()))
+    }
+
+    #[test]
+    fn test_multisig_access() {
+        let mut data = [0; TokenMultisig::LEN];
+        data[..3].copy_from_slice(&[2, 3, 1]);
+        let mut memory = account_memory(Address::default(), TOKEN_PROGRAM_ID, 1, &data);
+        let info = view(&mut memory);
+        let multisig = Account::<TokenMultisig>::try_from_info(&info)
+            .map_err(ProgramError::from)
+            .unwrap();
+        assert_eq!(multisig_data(&multisig), Ok((2, 3)));
+
+        data[2] = 0;
+        assert_eq!(
+            <SplStrategy as Accessor<TokenMultisig>>::access(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            <SplStrategy as Accessor<TokenMultisig>>::access(&[1; TokenMultisig::LEN + 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_token_account_as_multisig() {
+        // An initialized token account, whose data is too small for a multisig.
+        let mut data = [0; TokenAccount::LEN];
+        data[108] = 1;
+        let mut memory = account_memory(Address::default(), TOKEN_PROGRAM_ID, 1, &data);
+        let info = view(&mut memory);
+        let multisig = Account::<TokenMultisig>::try_from_info(&info)
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        assert_eq!(
+            multisig_data(&multisig),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            <SplStrategy as Accessor<TokenMultisig>>::access(&info.try_borrow().unwrap()).err(),
+            Some(ProgramError::AccountDataTooSmall)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use {
+    pinocchio::{instruction::InstructionAccount, AccountView},
+    typhoon_accounts::ReadableAccount,
+};
+
+/// Authority of a token instruction.
+///
+/// Any account is a single authority signing the instruction, while a
+/// [`MultisigAuthority`] passes a `TokenMultisig` with the signers approving it.
+pub trait TokenAuthority {
+    /// The account set as authority on the mint or token account.
+    fn authority_account(&self) -> &AccountView;
+
+    /// The signers of a multisig authority, empty for a single authority.
+    fn multisig_signers(&self) -> &[&AccountView];
+}
+
+impl<T: ReadableAccount> TokenAuthority for T {
+    #[inline(always)]
+    fn authority_account(&self) -> &AccountView {
+        self.as_ref()
+    }
+
+    #[inline(always)]
+    fn multisig_signers(&self) -> &[&AccountView] {
+        &[]
+    }
+}
+
+/// Multisig authority with the signers of the instruction, at least `m` of the multisig
+/// signers.
+#[derive(Clone, Copy)]
+pub struct MultisigAuthority<'a, 'b> {
+    pub multisig: &'a AccountView,
+    pub signers: &'b [&'a AccountView],
+}
+
+impl<'a, 'b> MultisigAuthority<'a, 'b> {
+    #[inline(always)]
+    pub fn new(multisig: &'a impl ReadableAccount, signers: &'b [&'a AccountView]) -> Self {
+        MultisigAuthority {
+            multisig: multisig.as_ref(),
+            signers,
+        }
+    }
+}
+
+impl TokenAuthority for MultisigAuthority<'_, '_> {
+    #[inline(always)]
+    fn authority_account(&self) -> &AccountView {
+        self.multisig
+    }
+
+    #[inline(always)]
+    fn multisig_signers(&self) -> &[&AccountView] {
+        self.signers
+    }
+}
+
+/// Instruction account of the `authority`, which only signs when it is not a multisig.
+#[inline(always)]
+pub(crate) fn authority_instruction_account(
+    authority: &impl TokenAuthority,
+) -> InstructionAccount<'_> {
+    InstructionAccount::new(
+        authority.authority_account().address(),
+        false,
+        authority.multisig_signers().is_empty(),
+    )
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "token2022")]
+use solana_address::address_eq;
+use {
+    crate::{TokenAccount, TokenAccountCpi, TokenAuthority},
+    pinocchio::{cpi::Signer as CpiSigner, error::ProgramError, hint::unlikely},
+    solana_address::Address,
+    typhoon_accounts::{Account, Mut, ReadableAccountData, WritableAccount},
+    typhoon_errors::Error,
+    typhoon_utility_traits::SystemCpi,
+};
+
+/// Mint of wrapped SOL of the SPL Token program, usable in `token::mint = NativeMint`
+/// constraints.
+///
+/// Token-2022 has its own native mint, see [`NativeMint2022`] and [`NativeMint::for_program`].
+pub struct NativeMint;
+
+impl NativeMint {
+    pub const ID: Address = Address::from_str_const("So11111111111111111111111111111111111111112");
+
+    #[inline(always)]
+    pub const fn address(&self) -> &'static Address {
+        &Self::ID
+    }
+
+    /// Returns the native mint of `token_program`.
+    #[inline(always)]
+    pub fn for_program(token_program: &Address) -> &'static Address {
+        #[cfg(feature = "token2022")]
+        if address_eq(token_program, &crate::TOKEN_2022_PROGRAM_ID) {
+            return &NativeMint2022::ID;
+        }
+        #[cfg(not(feature = "token2022"))]
+        let _ = token_program;
+
+        &Self::ID
+    }
+}
+
+/// Mint of wrapped SOL of the Token-2022 program, usable in `token::mint = NativeMint2022`
+/// constraints.
+#[cfg(feature = "token2022")]
+pub struct NativeMint2022;
+
+#[cfg(feature = "token2022")]
+impl NativeMint2022 {
+    pub const ID: Address = Address::from_str_const("9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP");
+
+    #[inline(always)]
+    pub const fn address(&self) -> &'static Address {
+        &Self::ID
+    }
+}
+
+/// Wraps `amount` lamports of `from` into the wrapped SOL account `to`.
+///
+/// The lamports are transferred with a system CPI, then `SyncNative` updates the token amount.
+#[inline]
+pub fn wrap_sol<'a>(
+    from: &impl SystemCpi<'a>,
+    to: &Mut<Account<'_, TokenAccount>>,
+    amount: u64,
+) -> Result<(), Error> {
+    from.transfer(to, amount)?;
+    to.sync_native()
+}
+
+/// Unwraps the wrapped SOL account `from` by closing it, sending all its lamports to
+/// `destination`.
+///
+/// The `authority` owning `from` signs the instruction, or a PDA of this program with the
+/// given `seeds`.
+#[inline]
+pub fn unwrap_sol(
+    from: &Mut<Account<'_, TokenAccount>>,
+    destination: &impl WritableAccount,
+    authority: &impl TokenAuthority,
+    seeds: Option<&[CpiSigner]>,
+) -> Result<(), Error> {
+    if unlikely(!from.data()?.is_native()) {
+        return Err(ProgramError::InvalidAccountData.into());
+    }
+
+    from.close(destination, authority, seeds)
+}
+
+#[cfg(all(test, feature = "token2022"))]
+mod tests {
+    use {super::*, pinocchio_token::ID as TOKEN_PROGRAM_ID};
+
+    #[test]
+    fn test_native_mint_for_program() {
+        assert_eq!(NativeMint::for_program(&TOKEN_PROGRAM_ID), &NativeMint::ID);
+        assert_eq!(
+            NativeMint::for_program(&crate::TOKEN_2022_PROGRAM_ID),
+            &NativeMint2022::ID
+        );
+        assert_ne!(NativeMint::ID, NativeMint2022::ID);
+    }
+}
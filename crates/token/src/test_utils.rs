@@ -0,0 +1,37 @@
+extern crate std;
+
+use {
+    pinocchio::{
+        account::{RuntimeAccount, NOT_BORROWED},
+        AccountView, Address,
+    },
+    std::{vec, vec::Vec},
+};
+
+/// Builds the memory of an account as laid out by the runtime, with room to grow.
+pub fn account_memory(address: Address, owner: Address, lamports: u64, data: &[u8]) -> Vec<u64> {
+    let header = size_of::<RuntimeAccount>();
+    let mut memory = vec![0u64; (header + data.len() + 10 * 1024).div_ceil(8)];
+    let raw = memory.as_mut_ptr() as *mut RuntimeAccount;
+    // SAFETY: The memory is aligned and large enough for the header and the data.
+    unsafe {
+        raw.write(RuntimeAccount {
+            borrow_state: NOT_BORROWED,
+            is_signer: 1,
+            is_writable: 1,
+            executable: 0,
+            resize_delta: 0,
+            address,
+            owner,
+            lamports,
+            data_len: data.len() as u64,
+        });
+        core::ptr::copy_nonoverlapping(data.as_ptr(), (raw as *mut u8).add(header), data.len());
+    }
+    memory
+}
+
+pub fn view(memory: &mut [u64]) -> AccountView {
+    // SAFETY: The memory was built by `account_memory`.
+    unsafe { AccountView::new_unchecked(memory.as_mut_ptr() as *mut RuntimeAccount) }
+}
@@ -1,13 +1,16 @@
 use {
-    crate::TokenAccount,
-    pinocchio::{cpi::Signer as CpiSigner, sysvars::rent::Rent, AccountView, Address},
+    crate::{invoke, TokenAccount, TokenProgram},
+    pinocchio::{
+        cpi::Signer as CpiSigner, error::ProgramError, hint::unlikely,
+        instruction::InstructionAccount, sysvars::rent::Rent, AccountView, Address,
+    },
     pinocchio_associated_token_account::instructions::{Create, CreateIdempotent},
-    pinocchio_token::{instructions::InitializeAccount3, ID as TOKEN_PROGRAM_ID},
     typhoon_accounts::{
         Account, FromAccountInfo, FromRaw, Mut, ReadableAccount, Signer, SignerCheck,
         SystemAccount, UncheckedAccount, WritableAccount,
     },
     typhoon_errors::Error,
+    typhoon_traits::CheckProgramId,
     typhoon_utility::create_account_with_minimum_balance_signed,
 };
 
@@ -16,30 +19,52 @@ where
     Self: Sized + Into<&'a AccountView>,
     T: ReadableAccount + FromAccountInfo<'a> + FromRaw<'a>,
 {
+    /// Creates a token account owned by `token_program`, which must match the program of the `mint`.
     fn create_token_account(
         self,
         rent: &Rent,
         payer: &impl WritableAccount,
         mint: &impl ReadableAccount,
         owner: &Address,
+        token_program: &impl ReadableAccount,
         seeds: Option<&[CpiSigner]>,
     ) -> Result<Mut<T>, Error> {
+        let program_id = token_program.address();
+        if unlikely(!TokenProgram::address_eq(program_id)) {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        if unlikely(!mint.owned_by(program_id)) {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+
+        // Token-2022 accounts hold the extensions required by the extensions of the mint.
+        #[cfg(feature = "token2022")]
+        let space = crate::token_account_space(&mint.as_ref().try_borrow()?)?;
+        #[cfg(not(feature = "token2022"))]
+        let space = TokenAccount::LEN;
+
         let info = self.into();
         create_account_with_minimum_balance_signed(
             info,
-            TokenAccount::LEN,
-            &TOKEN_PROGRAM_ID,
+            space,
+            program_id,
             payer.as_ref(),
             rent,
             seeds.unwrap_or_default(),
         )?;
 
-        InitializeAccount3 {
-            account: info,
-            mint: mint.as_ref(),
-            owner,
-        }
-        .invoke()?;
+        let mut data = [18; 33];
+        data[1..].copy_from_slice(owner.as_ref());
+        invoke(
+            program_id,
+            [
+                InstructionAccount::writable(info.address()),
+                InstructionAccount::readonly(mint.address()),
+            ],
+            [info, mint.as_ref()],
+            &data,
+            None,
+        )?;
 
         Ok(Mut::from_raw_info(info))
     }
@@ -102,3 +127,97 @@ macro_rules! impl_trait {
 impl_trait!(&'a AccountView);
 impl_trait!(SystemAccount<'a>);
 impl_trait!(UncheckedAccount<'a>);
+
+#[cfg(all(test, feature = "token2022"))]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            test_utils::{account_memory, view},
+            BaseState, ExtensionType, TOKEN_2022_PROGRAM_ID,
+        },
+    };
+
+    #[test]
+    fn test_create_token_account_for_transfer_fee_mint() {
+        #[repr(C, align(8))]
+        struct RentData([u8; 16]);
+
+        let mut rent_data = RentData([0; 16]);
+        rent_data.0[..8].copy_from_slice(&3480u64.to_le_bytes());
+        rent_data.0[8..].copy_from_slice(&2f64.to_le_bytes());
+        let rent = Rent::from_bytes(&rent_data.0).unwrap();
+
+        // A mint holding a `TransferFeeConfig` extension.
+        let mut mint_data = [0; TokenAccount::LEN + 1 + 4 + 108];
+        mint_data[TokenAccount::LEN] = 1;
+        mint_data[TokenAccount::LEN + 1..TokenAccount::LEN + 5].copy_from_slice(&[1, 0, 108, 0]);
+
+        let mut mint_memory = account_memory(
+            Address::new_from_array([1; 32]),
+            TOKEN_2022_PROGRAM_ID,
+            1,
+            &mint_data,
+        );
+        let mut other_mint_memory = account_memory(
+            Address::new_from_array([5; 32]),
+            Address::default(),
+            1,
+            &mint_data,
+        );
+        let mut payer_memory = account_memory(
+            Address::new_from_array([2; 32]),
+            Address::default(),
+            1_000_000_000,
+            &[],
+        );
+        let mut program_memory = account_memory(TOKEN_2022_PROGRAM_ID, Address::default(), 1, &[]);
+        // Already funded, so it is assigned and allocated instead of created.
+        let mut account_memory =
+            account_memory(Address::new_from_array([3; 32]), Address::default(), 1, &[]);
+        let mint = view(&mut mint_memory);
+        let payer = view(&mut payer_memory);
+        let program = view(&mut program_memory);
+        let account = view(&mut account_memory);
+
+        let unchecked = |view| {
+            UncheckedAccount::try_from_info(view)
+                .map_err(ProgramError::from)
+                .unwrap()
+        };
+
+        let created: Result<Mut<Account<TokenAccount>>, Error> = (&account).create_token_account(
+            rent,
+            &Mut::<UncheckedAccount>::try_from_info(&payer)
+                .map_err(ProgramError::from)
+                .unwrap(),
+            &unchecked(&mint),
+            &Address::new_from_array([4; 32]),
+            &unchecked(&program),
+            None,
+        );
+
+        assert!(created.is_ok());
+        assert_eq!(
+            account.data_len(),
+            TokenAccount::space_with_extensions(&[ExtensionType::TransferFeeAmount])
+        );
+
+        // The mint belongs to another program.
+        let other_mint = view(&mut other_mint_memory);
+        let created: Result<Mut<Account<TokenAccount>>, Error> = (&account).create_token_account(
+            rent,
+            &Mut::<UncheckedAccount>::try_from_info(&payer)
+                .map_err(ProgramError::from)
+                .unwrap(),
+            &unchecked(&other_mint),
+            &Address::new_from_array([4; 32]),
+            &unchecked(&program),
+            None,
+        );
+        assert_eq!(
+            created.err().map(ProgramError::from),
+            Some(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -1,12 +1,17 @@
+#[cfg(feature = "token2022")]
+use crate::extensions::{space, MintExtensionConfig};
 use {
-    crate::Mint,
-    pinocchio::{cpi::Signer as CpiSigner, sysvars::rent::Rent, AccountView, Address},
-    pinocchio_token::{instructions::InitializeMint2, ID as TOKEN_PROGRAM_ID},
+    crate::{invoke, Mint, TokenProgram},
+    pinocchio::{
+        cpi::Signer as CpiSigner, error::ProgramError, hint::unlikely,
+        instruction::InstructionAccount, sysvars::rent::Rent, AccountView, Address,
+    },
     typhoon_accounts::{
         Account, FromAccountInfo, Mut, ReadableAccount, Signer, SignerCheck, SystemAccount,
         UncheckedAccount, WritableAccount,
     },
     typhoon_errors::Error,
+    typhoon_traits::CheckProgramId,
     typhoon_utility::create_account_with_minimum_balance_signed,
 };
 
@@ -15,7 +20,9 @@ where
     Self: Sized + Into<&'a AccountView>,
     T: ReadableAccount + FromAccountInfo<'a>,
 {
+    /// Creates a mint owned by `token_program`.
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     fn create_mint(
         self,
         rent: &Rent,
@@ -23,30 +30,102 @@ where
         mint_authority: &Address,
         decimals: u8,
         freeze_authority: Option<&Address>,
+        token_program: &impl ReadableAccount,
         seeds: Option<&[CpiSigner]>,
     ) -> Result<Mut<T>, Error> {
+        let program_id = token_program.address();
+        if unlikely(!TokenProgram::address_eq(program_id)) {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+
         let info = self.into();
         create_account_with_minimum_balance_signed(
             info,
             Mint::LEN,
-            &TOKEN_PROGRAM_ID,
+            program_id,
+            payer.as_ref(),
+            rent,
+            seeds.unwrap_or_default(),
+        )?;
+
+        initialize_mint(info, program_id, mint_authority, decimals, freeze_authority)?;
+
+        Mut::try_from_info(info)
+    }
+
+    /// Creates a Token-2022 mint owned by `token_program`, initializing the `extensions` first.
+    #[cfg(feature = "token2022")]
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn create_mint_with_extensions(
+        self,
+        rent: &Rent,
+        payer: &impl WritableAccount,
+        mint_authority: &Address,
+        decimals: u8,
+        freeze_authority: Option<&Address>,
+        extensions: &[MintExtensionConfig],
+        token_program: &impl ReadableAccount,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<Mut<T>, Error> {
+        let program_id = token_program.address();
+        if unlikely(!TokenProgram::address_eq(program_id)) {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+
+        let info = self.into();
+        create_account_with_minimum_balance_signed(
+            info,
+            space(
+                Mint::LEN,
+                extensions
+                    .iter()
+                    .map(|extension| extension.extension_type().len()),
+            ),
+            program_id,
             payer.as_ref(),
             rent,
             seeds.unwrap_or_default(),
         )?;
 
-        InitializeMint2 {
-            mint: info,
-            mint_authority,
-            decimals,
-            freeze_authority,
+        for extension in extensions {
+            extension.initialize(info, program_id)?;
         }
-        .invoke()?;
+        initialize_mint(info, program_id, mint_authority, decimals, freeze_authority)?;
 
         Mut::try_from_info(info)
     }
 }
 
+#[inline(always)]
+fn initialize_mint(
+    info: &AccountView,
+    program_id: &Address,
+    mint_authority: &Address,
+    decimals: u8,
+    freeze_authority: Option<&Address>,
+) -> Result<(), Error> {
+    let mut data = [0; 67];
+    data[0] = 20;
+    data[1] = decimals;
+    data[2..34].copy_from_slice(mint_authority.as_ref());
+    let len = match freeze_authority {
+        Some(freeze_authority) => {
+            data[34] = 1;
+            data[35..].copy_from_slice(freeze_authority.as_ref());
+            67
+        }
+        None => 35,
+    };
+    invoke(
+        program_id,
+        [InstructionAccount::writable(info.address())],
+        [info],
+        &data[..len],
+        None,
+    )
+}
+
 macro_rules! impl_trait {
     ($origin: ty) => {
         impl<'a> SplCreateMint<'a, Account<'a, Mint>> for $origin {}
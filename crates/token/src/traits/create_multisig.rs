@@ -0,0 +1,73 @@
+use {
+    crate::{invoke_with_signers, TokenMultisig, TokenProgram},
+    pinocchio::{
+        cpi::Signer as CpiSigner, error::ProgramError, hint::unlikely,
+        instruction::InstructionAccount, sysvars::rent::Rent, AccountView,
+    },
+    typhoon_accounts::{
+        Account, FromAccountInfo, Mut, ReadableAccount, Signer, SignerCheck, SystemAccount,
+        UncheckedAccount, WritableAccount,
+    },
+    typhoon_errors::Error,
+    typhoon_traits::CheckProgramId,
+    typhoon_utility::create_account_with_minimum_balance_signed,
+};
+
+pub trait SplCreateMultisig<'a, T>
+where
+    Self: Sized + Into<&'a AccountView>,
+    T: ReadableAccount + FromAccountInfo<'a>,
+{
+    /// Creates a multisig owned by `token_program`, requiring `m` of the `signers` to sign.
+    #[inline]
+    fn create_multisig(
+        self,
+        rent: &Rent,
+        payer: &impl WritableAccount,
+        signers: &[&AccountView],
+        m: u8,
+        token_program: &impl ReadableAccount,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<Mut<T>, Error> {
+        let program_id = token_program.address();
+        if unlikely(!TokenProgram::address_eq(program_id)) {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+
+        let info = self.into();
+        create_account_with_minimum_balance_signed(
+            info,
+            TokenMultisig::LEN,
+            program_id,
+            payer.as_ref(),
+            rent,
+            seeds.unwrap_or_default(),
+        )?;
+
+        invoke_with_signers(
+            program_id,
+            [InstructionAccount::writable(info.address())],
+            [info],
+            signers,
+            false,
+            &[19, m],
+            None,
+        )?;
+
+        Mut::try_from_info(info)
+    }
+}
+
+macro_rules! impl_trait {
+    ($origin: ty) => {
+        impl<'a> SplCreateMultisig<'a, Account<'a, TokenMultisig>> for $origin {}
+        impl<'a, C> SplCreateMultisig<'a, Signer<'a, Account<'a, TokenMultisig>, C>> for $origin where
+            C: SignerCheck
+        {
+        }
+    };
+}
+
+impl_trait!(&'a AccountView);
+impl_trait!(SystemAccount<'a>);
+impl_trait!(UncheckedAccount<'a>);
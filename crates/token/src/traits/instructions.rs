@@ -0,0 +1,367 @@
+use {
+    crate::{
+        authority_instruction_account, invoke, invoke_with_signers,
+        spl_instructions::AuthorityType, token_program_id, Mint, TokenAccount, TokenAuthority,
+    },
+    pinocchio::{cpi::Signer as CpiSigner, instruction::InstructionAccount, AccountView, Address},
+    typhoon_accounts::{Account, Mut, ReadableAccount, WritableAccount},
+    typhoon_errors::Error,
+};
+
+#[inline(always)]
+fn set_authority(
+    account: &AccountView,
+    authority: &impl TokenAuthority,
+    authority_type: AuthorityType,
+    new_authority: Option<&Address>,
+    seeds: Option<&[CpiSigner]>,
+) -> Result<(), Error> {
+    let mut data = [0; 35];
+    data[0] = 6;
+    data[1] = authority_type as u8;
+    let len = match new_authority {
+        Some(new_authority) => {
+            data[2] = 1;
+            data[3..].copy_from_slice(new_authority.as_ref());
+            35
+        }
+        None => 3,
+    };
+
+    invoke_with_signers(
+        token_program_id(account),
+        [
+            InstructionAccount::writable(account.address()),
+            authority_instruction_account(authority),
+        ],
+        [account, authority.authority_account()],
+        authority.multisig_signers(),
+        true,
+        &data[..len],
+        seeds,
+    )
+}
+
+/// Token program instructions on a token account.
+///
+/// The instructions target the token program owning the account. The `authority`
+/// signs the instruction, or a PDA of this program with the given `seeds`. A
+/// [`MultisigAuthority`](crate::MultisigAuthority) passes a multisig with its signers.
+pub trait TokenAccountCpi: WritableAccount {
+    #[inline]
+    fn transfer_checked(
+        &self,
+        mint: &impl ReadableAccount,
+        to: &impl WritableAccount,
+        authority: &impl TokenAuthority,
+        amount: u64,
+        decimals: u8,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = [12; 10];
+        data[1..9].copy_from_slice(&amount.to_le_bytes());
+        data[9] = decimals;
+
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly(mint.address()),
+                InstructionAccount::writable(to.address()),
+                authority_instruction_account(authority),
+            ],
+            [
+                self.as_ref(),
+                mint.as_ref(),
+                to.as_ref(),
+                authority.authority_account(),
+            ],
+            authority.multisig_signers(),
+            true,
+            &data,
+            seeds,
+        )
+    }
+
+    /// Transfers from a Token-2022 account with a `TransferFeeConfig` mint, asserting the
+    /// expected `fee` which is withheld on the destination.
+    #[cfg(feature = "token2022")]
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_checked_with_fee(
+        &self,
+        mint: &impl ReadableAccount,
+        to: &impl WritableAccount,
+        authority: &impl TokenAuthority,
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = [0; 19];
+        data[0] = 26;
+        data[1] = 1;
+        data[2..10].copy_from_slice(&amount.to_le_bytes());
+        data[10] = decimals;
+        data[11..].copy_from_slice(&fee.to_le_bytes());
+
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly(mint.address()),
+                InstructionAccount::writable(to.address()),
+                authority_instruction_account(authority),
+            ],
+            [
+                self.as_ref(),
+                mint.as_ref(),
+                to.as_ref(),
+                authority.authority_account(),
+            ],
+            authority.multisig_signers(),
+            true,
+            &data,
+            seeds,
+        )
+    }
+
+    #[inline]
+    fn burn(
+        &self,
+        mint: &impl WritableAccount,
+        authority: &impl TokenAuthority,
+        amount: u64,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = [8; 9];
+        data[1..].copy_from_slice(&amount.to_le_bytes());
+
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::writable(mint.address()),
+                authority_instruction_account(authority),
+            ],
+            [self.as_ref(), mint.as_ref(), authority.authority_account()],
+            authority.multisig_signers(),
+            true,
+            &data,
+            seeds,
+        )
+    }
+
+    #[inline]
+    fn approve(
+        &self,
+        delegate: &impl ReadableAccount,
+        owner: &impl TokenAuthority,
+        amount: u64,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = [4; 9];
+        data[1..].copy_from_slice(&amount.to_le_bytes());
+
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly(delegate.address()),
+                authority_instruction_account(owner),
+            ],
+            [self.as_ref(), delegate.as_ref(), owner.authority_account()],
+            owner.multisig_signers(),
+            true,
+            &data,
+            seeds,
+        )
+    }
+
+    #[inline]
+    fn revoke(
+        &self,
+        owner: &impl TokenAuthority,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                authority_instruction_account(owner),
+            ],
+            [self.as_ref(), owner.authority_account()],
+            owner.multisig_signers(),
+            true,
+            &[5],
+            seeds,
+        )
+    }
+
+    /// Sets the `AccountOwner` or `CloseAccount` authority, removed when `new_authority` is `None`.
+    #[inline]
+    fn set_authority(
+        &self,
+        authority: &impl TokenAuthority,
+        authority_type: AuthorityType,
+        new_authority: Option<&Address>,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        set_authority(
+            self.as_ref(),
+            authority,
+            authority_type,
+            new_authority,
+            seeds,
+        )
+    }
+
+    #[inline]
+    fn freeze(
+        &self,
+        mint: &impl ReadableAccount,
+        freeze_authority: &impl TokenAuthority,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly(mint.address()),
+                authority_instruction_account(freeze_authority),
+            ],
+            [
+                self.as_ref(),
+                mint.as_ref(),
+                freeze_authority.authority_account(),
+            ],
+            freeze_authority.multisig_signers(),
+            true,
+            &[10],
+            seeds,
+        )
+    }
+
+    #[inline]
+    fn thaw(
+        &self,
+        mint: &impl ReadableAccount,
+        freeze_authority: &impl TokenAuthority,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::readonly(mint.address()),
+                authority_instruction_account(freeze_authority),
+            ],
+            [
+                self.as_ref(),
+                mint.as_ref(),
+                freeze_authority.authority_account(),
+            ],
+            freeze_authority.multisig_signers(),
+            true,
+            &[11],
+            seeds,
+        )
+    }
+
+    /// Closes the empty token account and sends its lamports to `destination`.
+    #[inline]
+    fn close(
+        &self,
+        destination: &impl WritableAccount,
+        authority: &impl TokenAuthority,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::writable(destination.address()),
+                authority_instruction_account(authority),
+            ],
+            [
+                self.as_ref(),
+                destination.as_ref(),
+                authority.authority_account(),
+            ],
+            authority.multisig_signers(),
+            true,
+            &[9],
+            seeds,
+        )
+    }
+
+    /// Syncs the amount of a wrapped SOL account with its lamports.
+    #[inline]
+    fn sync_native(&self) -> Result<(), Error> {
+        invoke(
+            token_program_id(self.as_ref()),
+            [InstructionAccount::writable(self.address())],
+            [self.as_ref()],
+            &[17],
+            None,
+        )
+    }
+}
+
+/// Token program instructions on a mint.
+///
+/// The instructions target the token program owning the mint. The `authority`
+/// signs the instruction, or a PDA of this program with the given `seeds`. A
+/// [`MultisigAuthority`](crate::MultisigAuthority) passes a multisig with its signers.
+pub trait MintCpi: WritableAccount {
+    #[inline]
+    fn mint_to(
+        &self,
+        to: &impl WritableAccount,
+        mint_authority: &impl TokenAuthority,
+        amount: u64,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        let mut data = [7; 9];
+        data[1..].copy_from_slice(&amount.to_le_bytes());
+
+        invoke_with_signers(
+            token_program_id(self.as_ref()),
+            [
+                InstructionAccount::writable(self.address()),
+                InstructionAccount::writable(to.address()),
+                authority_instruction_account(mint_authority),
+            ],
+            [
+                self.as_ref(),
+                to.as_ref(),
+                mint_authority.authority_account(),
+            ],
+            mint_authority.multisig_signers(),
+            true,
+            &data,
+            seeds,
+        )
+    }
+
+    /// Sets the `MintTokens` or `FreezeAccount` authority, removed when `new_authority` is `None`.
+    #[inline]
+    fn set_authority(
+        &self,
+        authority: &impl TokenAuthority,
+        authority_type: AuthorityType,
+        new_authority: Option<&Address>,
+        seeds: Option<&[CpiSigner]>,
+    ) -> Result<(), Error> {
+        set_authority(
+            self.as_ref(),
+            authority,
+            authority_type,
+            new_authority,
+            seeds,
+        )
+    }
+}
+
+impl TokenAccountCpi for Mut<Account<'_, TokenAccount>> {}
+impl MintCpi for Mut<Account<'_, Mint>> {}
@@ -1,4 +1,6 @@
 mod create_account;
 mod create_mint;
+mod create_multisig;
+mod instructions;
 
-pub use {create_account::*, create_mint::*};
+pub use {create_account::*, create_mint::*, create_multisig::*, instructions::*};
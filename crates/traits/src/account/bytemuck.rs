@@ -1,24 +1,281 @@
 use {
     crate::{Accessor, MutAccessor},
-    bytemuck::{try_from_bytes, try_from_bytes_mut, AnyBitPattern, NoUninit},
+    bytemuck::{
+        checked::{self, CheckedCastError},
+        pod_read_unaligned, try_from_bytes, try_from_bytes_mut, AnyBitPattern, CheckedBitPattern,
+        NoUninit, PodCastError,
+    },
+    core::{
+        marker::PhantomData,
+        ops::{Deref, DerefMut},
+    },
     solana_program_error::ProgramError,
+    typhoon_errors::ErrorCode,
 };
 
 pub struct BytemuckStrategy;
 
+#[inline(always)]
+fn cast_error(error: PodCastError) -> ProgramError {
+    match error {
+        PodCastError::SizeMismatch => ErrorCode::InvalidDataLength.into(),
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned => {
+            ErrorCode::InvalidDataAlignment.into()
+        }
+        _ => ProgramError::BorshIoError,
+    }
+}
+
+/// Value read from instruction data, borrowed when the data is aligned for `T` and copied
+/// otherwise.
+#[derive(Debug, Clone, Copy)]
+pub enum PodRef<'a, T> {
+    Borrowed(&'a T),
+    Owned(T),
+}
+
+impl<T> Deref for PodRef<'_, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            PodRef::Borrowed(value) => value,
+            PodRef::Owned(value) => value,
+        }
+    }
+}
+
 impl<'a, T> Accessor<'a, T> for BytemuckStrategy
 where
     T: AnyBitPattern,
 {
     type Data = &'a T;
+    type Read = PodRef<'a, T>;
+
+    #[inline(always)]
+    fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
+        try_from_bytes(data).map_err(cast_error)
+    }
+
+    #[inline(always)]
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
+        let Some((to_read, rem)) = data.split_at_checked(core::mem::size_of::<T>()) else {
+            return Err(ErrorCode::InvalidDataLength.into());
+        };
+        *data = rem;
+
+        let ptr = to_read.as_ptr() as *const T;
+        if const { core::mem::align_of::<T>() == 1 } || ptr.is_aligned() {
+            // SAFETY: The size and the alignment were checked and `T` is valid for any bit pattern.
+            Ok(PodRef::Borrowed(unsafe { &*ptr }))
+        } else {
+            Ok(PodRef::Owned(pod_read_unaligned(to_read)))
+        }
+    }
+}
+
+impl<'a, T> MutAccessor<'a, T> for BytemuckStrategy
+where
+    T: NoUninit + AnyBitPattern,
+{
+    type Data = &'a mut T;
+
+    #[inline(always)]
+    fn access_mut(data: &'a mut [u8]) -> Result<Self::Data, ProgramError> {
+        try_from_bytes_mut(data).map_err(cast_error)
+    }
+}
+
+/// Strategy for types with invalid bit patterns, e.g. `bool` or enum fields, validated with
+/// `CheckedBitPattern` on every access.
+pub struct CheckedBytemuckStrategy;
+
+#[inline(always)]
+fn checked_error(error: CheckedCastError) -> ProgramError {
+    match error {
+        CheckedCastError::PodCastError(error) => cast_error(error),
+        CheckedCastError::InvalidBitPattern => ProgramError::InvalidAccountData,
+    }
+}
+
+impl<'a, T> Accessor<'a, T> for CheckedBytemuckStrategy
+where
+    T: CheckedBitPattern + 'a,
+{
+    type Data = &'a T;
+    type Read = PodRef<'a, T>;
 
     #[inline(always)]
     fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
-        try_from_bytes(data).map_err(|_| ProgramError::BorshIoError)
+        checked::try_from_bytes(data).map_err(checked_error)
     }
 
     #[inline(always)]
-    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError> {
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
+        let Some((to_read, rem)) = data.split_at_checked(core::mem::size_of::<T>()) else {
+            return Err(ErrorCode::InvalidDataLength.into());
+        };
+        *data = rem;
+
+        if const { core::mem::align_of::<T>() == 1 } || (to_read.as_ptr() as *const T).is_aligned()
+        {
+            checked::try_from_bytes(to_read)
+                .map(PodRef::Borrowed)
+                .map_err(checked_error)
+        } else {
+            checked::try_pod_read_unaligned(to_read)
+                .map(PodRef::Owned)
+                .map_err(checked_error)
+        }
+    }
+}
+
+impl<'a, T> MutAccessor<'a, T> for CheckedBytemuckStrategy
+where
+    T: CheckedBitPattern + NoUninit + 'a,
+{
+    type Data = &'a mut T;
+
+    #[inline(always)]
+    fn access_mut(data: &'a mut [u8]) -> Result<Self::Data, ProgramError> {
+        checked::try_from_bytes_mut(data).map_err(checked_error)
+    }
+}
+
+/// Trait for `#[repr(u8)]` enum states read in place from account data.
+///
+/// # Safety
+///
+/// The enum must have an alignment of 1 and every variant payload must be valid for any
+/// bit pattern, so that any data of the right size starting with a valid tag is a valid value.
+/// `write_tagged` must write the tag of the variant followed by its fields in declaration order.
+pub unsafe trait TaggedState: Sized {
+    /// Returns `true` if `tag` is the tag of one of the variants.
+    fn is_valid_tag(tag: u8) -> bool;
+
+    /// Writes the value into `data` of the size of the enum, zeroing the bytes after the
+    /// payload of its variant.
+    fn write_tagged(&self, data: &mut [u8]);
+}
+
+/// Checks at compile time that a variant payload can be read in place.
+#[doc(hidden)]
+pub const fn assert_tagged_payload<T: AnyBitPattern + NoUninit>() {}
+
+/// Returns the bytes of a variant field, used by the generated `TaggedState::write_tagged`.
+#[doc(hidden)]
+#[inline(always)]
+pub fn tagged_field_bytes<T: AnyBitPattern + NoUninit>(field: &T) -> &[u8] {
+    bytemuck::bytes_of(field)
+}
+
+/// Writes the `tag` and the `fields` of a variant, zeroing the remaining bytes of `data`.
+#[doc(hidden)]
+#[inline(always)]
+pub fn write_tagged_variant(data: &mut [u8], tag: u8, fields: &[&[u8]]) {
+    data[0] = tag;
+    let mut offset = 1;
+    for field in fields {
+        data[offset..offset + field.len()].copy_from_slice(field);
+        offset += field.len();
+    }
+    data[offset..].fill(0);
+}
+
+/// Mutable view of a `#[repr(u8)]` enum state in place.
+///
+/// The variant can only be changed with [`TaggedMut::set`], which zeroes the bytes not used by
+/// the new variant, since variants may have payloads of different sizes.
+pub struct TaggedMut<D, T> {
+    data: D,
+    _phantom: PhantomData<T>,
+}
+
+impl<D, T> TaggedMut<D, T>
+where
+    D: DerefMut<Target = [u8]>,
+    T: TaggedState,
+{
+    #[inline(always)]
+    pub fn try_new(data: D) -> Result<Self, ProgramError> {
+        TaggedStrategy::check::<T>(&data)?;
+
+        Ok(TaggedMut {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Replaces the state with `value`.
+    #[inline(always)]
+    pub fn set(&mut self, value: T) {
+        value.write_tagged(&mut self.data);
+    }
+
+    /// Returns the payload selected by `variant`, e.g. `Order::as_filled`, to update it in
+    /// place, or `None` if the state is another variant.
+    #[inline(always)]
+    pub fn payload_mut<P>(&mut self, variant: impl FnOnce(&T) -> Option<&P>) -> Option<&mut P>
+    where
+        P: AnyBitPattern + NoUninit,
+    {
+        let payload = variant(self)? as *const P as usize;
+        let offset = payload.checked_sub(self.data.as_ptr() as usize)?;
+        if offset == 0 || offset + core::mem::size_of::<P>() > self.data.len() {
+            return None;
+        }
+
+        // SAFETY: `P` is borrowed from the payload of the current variant, which stays valid
+        // for any bit pattern of `P`, and the tag is not part of it.
+        Some(unsafe { &mut *(self.data.as_mut_ptr().add(offset) as *mut P) })
+    }
+}
+
+impl<D, T> Deref for TaggedMut<D, T>
+where
+    D: Deref<Target = [u8]>,
+    T: TaggedState,
+{
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The size and the tag were checked when the view was created and the tag is
+        // only written with a valid variant, see `TaggedState`.
+        unsafe { &*(self.data.as_ptr() as *const T) }
+    }
+}
+
+pub struct TaggedStrategy;
+
+impl TaggedStrategy {
+    #[inline(always)]
+    fn check<T: TaggedState>(data: &[u8]) -> Result<(), ProgramError> {
+        match data.first() {
+            Some(tag) if data.len() == core::mem::size_of::<T>() && T::is_valid_tag(*tag) => Ok(()),
+            _ => Err(ProgramError::BorshIoError),
+        }
+    }
+}
+
+impl<'a, T> Accessor<'a, T> for TaggedStrategy
+where
+    T: TaggedState + 'a,
+{
+    type Data = &'a T;
+    type Read = &'a T;
+
+    #[inline(always)]
+    fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
+        Self::check::<T>(data)?;
+
+        // SAFETY: The size and the tag were checked, see `TaggedState`.
+        Ok(unsafe { &*(data.as_ptr() as *const T) })
+    }
+
+    #[inline(always)]
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
         let len = core::mem::size_of::<T>();
         if data.len() < len {
             return Err(ProgramError::InvalidInstructionData);
@@ -26,17 +283,82 @@ where
 
         let (to_read, rem) = unsafe { data.split_at_unchecked(len) };
         *data = rem;
-        try_from_bytes(to_read).map_err(|_| ProgramError::BorshIoError)
+        Self::access(to_read)
     }
 }
-impl<'a, T> MutAccessor<'a, T> for BytemuckStrategy
+
+impl<'a, T> MutAccessor<'a, T> for TaggedStrategy
 where
-    T: NoUninit + AnyBitPattern,
+    T: TaggedState + 'a,
 {
-    type Data = &'a mut T;
+    type Data = TaggedMut<&'a mut [u8], T>;
 
     #[inline(always)]
     fn access_mut(data: &'a mut [u8]) -> Result<Self::Data, ProgramError> {
-        try_from_bytes_mut(data).map_err(|_| ProgramError::BorshIoError)
+        TaggedMut::try_new(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_unaligned() {
+        #[repr(C, align(8))]
+        struct Aligned([u8; 24]);
+
+        let mut buffer = Aligned([0; 24]);
+        buffer.0[1..9].copy_from_slice(&42u64.to_le_bytes());
+        buffer.0[16..].copy_from_slice(&7u64.to_le_bytes());
+
+        let mut data = &buffer.0[1..];
+        let value = <BytemuckStrategy as Accessor<u64>>::read(&mut data).unwrap();
+        assert!(matches!(value, PodRef::Owned(42)));
+        assert_eq!(data.len(), 15);
+
+        let mut data = &buffer.0[16..];
+        let value = <BytemuckStrategy as Accessor<u64>>::read(&mut data).unwrap();
+        assert!(matches!(value, PodRef::Borrowed(&7)));
+        assert!(data.is_empty());
+
+        assert_eq!(
+            <BytemuckStrategy as Accessor<u64>>::read(&mut &buffer.0[..4]).err(),
+            Some(ErrorCode::InvalidDataLength.into())
+        );
+        assert_eq!(
+            <BytemuckStrategy as Accessor<u64>>::access(&buffer.0[1..9]).err(),
+            Some(ErrorCode::InvalidDataAlignment.into())
+        );
+
+        // Types with an alignment of 1 are borrowed at any offset.
+        let mut data = &buffer.0[1..];
+        let value = <BytemuckStrategy as Accessor<[u8; 8]>>::read(&mut data).unwrap();
+        assert!(matches!(
+            value,
+            PodRef::Borrowed(&[42, 0, 0, 0, 0, 0, 0, 0])
+        ));
+    }
+
+    #[test]
+    fn test_checked_access() {
+        let mut data = [1u8, 2];
+        assert_eq!(
+            <CheckedBytemuckStrategy as Accessor<bool>>::access(&data[..1]),
+            Ok(&true)
+        );
+        assert_eq!(
+            <CheckedBytemuckStrategy as Accessor<bool>>::access(&data[1..]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        *<CheckedBytemuckStrategy as MutAccessor<bool>>::access_mut(&mut data[..1]).unwrap() =
+            false;
+        assert_eq!(data[0], 0);
+
+        let mut data = &[1u8, 0][..];
+        let value = <CheckedBytemuckStrategy as Accessor<bool>>::read(&mut data).unwrap();
+        assert!(*value);
+        assert_eq!(data, [0]);
     }
 }
@@ -1,18 +1,21 @@
 #[cfg(feature = "bytemuck")]
 mod bytemuck;
+mod packed;
 mod wincode;
 
 #[cfg(feature = "bytemuck")]
 pub use bytemuck::*;
-pub use wincode::*;
 use {::wincode::io::Writer, solana_program_error::ProgramError};
+pub use {packed::*, wincode::*};
 
 pub trait Accessor<'a, T> {
     type Data: 'a;
+    /// Value read from instruction data, which may not be aligned for `T`.
+    type Read: 'a;
 
     fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError>;
 
-    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError>;
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError>;
 }
 
 pub trait MutAccessor<'a, T> {
@@ -0,0 +1,203 @@
+use {
+    crate::{Accessor, MutAccessor},
+    core::ops::Deref,
+    solana_address::Address,
+    solana_program_error::ProgramError,
+    typhoon_errors::ErrorCode,
+};
+
+/// Type stored in a packed little-endian layout, without padding or alignment.
+///
+/// `Option<T>` uses the SPL `COption` layout: a 4-byte tag followed by `T`, zeroed when `None`.
+pub trait Packed: Sized {
+    /// The packed size in bytes.
+    const LEN: usize;
+
+    /// Decodes a value from exactly `LEN` bytes.
+    fn unpack(src: &[u8]) -> Result<Self, ProgramError>;
+
+    /// Encodes the value into exactly `LEN` bytes.
+    fn pack(&self, dst: &mut [u8]);
+}
+
+#[inline(always)]
+fn array<const N: usize>(src: &[u8]) -> Result<[u8; N], ProgramError> {
+    src.try_into()
+        .map_err(|_| ErrorCode::InvalidDataLength.into())
+}
+
+macro_rules! impl_packed_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Packed for $ty {
+                const LEN: usize = core::mem::size_of::<$ty>();
+
+                #[inline(always)]
+                fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+                    array(src).map(<$ty>::from_le_bytes)
+                }
+
+                #[inline(always)]
+                fn pack(&self, dst: &mut [u8]) {
+                    dst.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Packed for bool {
+    const LEN: usize = 1;
+
+    #[inline(always)]
+    fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        match array(src)? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    #[inline(always)]
+    fn pack(&self, dst: &mut [u8]) {
+        dst[0] = *self as u8;
+    }
+}
+
+impl<const N: usize> Packed for [u8; N] {
+    const LEN: usize = N;
+
+    #[inline(always)]
+    fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        array(src)
+    }
+
+    #[inline(always)]
+    fn pack(&self, dst: &mut [u8]) {
+        dst.copy_from_slice(self);
+    }
+}
+
+impl Packed for Address {
+    const LEN: usize = 32;
+
+    #[inline(always)]
+    fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        array(src).map(Address::new_from_array)
+    }
+
+    #[inline(always)]
+    fn pack(&self, dst: &mut [u8]) {
+        dst.copy_from_slice(self.as_ref());
+    }
+}
+
+impl<T: Packed> Packed for Option<T> {
+    const LEN: usize = 4 + T::LEN;
+
+    #[inline(always)]
+    fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        if src.len() != Self::LEN {
+            return Err(ErrorCode::InvalidDataLength.into());
+        }
+
+        let (tag, value) = src.split_at(4);
+        match tag {
+            [0, 0, 0, 0] => Ok(None),
+            [1, 0, 0, 0] => T::unpack(value).map(Some),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    #[inline(always)]
+    fn pack(&self, dst: &mut [u8]) {
+        let (tag, value) = dst.split_at_mut(4);
+        match self {
+            Some(inner) => {
+                tag.copy_from_slice(&1u32.to_le_bytes());
+                inner.pack(value);
+            }
+            None => {
+                tag.fill(0);
+                value.fill(0);
+            }
+        }
+    }
+}
+
+/// Packed state accessed field by field through a view over its bytes, see `PackedState` derive.
+pub trait PackedState: Packed {
+    /// View with a `get_` accessor per field, and a `set_` one when `B` is mutable.
+    type View<B: Deref<Target = [u8]>>;
+
+    /// Creates a view over `data`, which must hold at least `LEN` bytes.
+    fn view<B: Deref<Target = [u8]>>(data: B) -> Result<Self::View<B>, ProgramError>;
+}
+
+pub struct PackedStrategy;
+
+impl<'a, T> Accessor<'a, T> for PackedStrategy
+where
+    T: PackedState + 'a,
+{
+    type Data = T::View<&'a [u8]>;
+    type Read = T::View<&'a [u8]>;
+
+    #[inline(always)]
+    fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
+        T::view(data)
+    }
+
+    #[inline(always)]
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
+        let Some((to_read, rem)) = data.split_at_checked(T::LEN) else {
+            return Err(ErrorCode::InvalidDataLength.into());
+        };
+        *data = rem;
+        T::view(to_read)
+    }
+}
+
+impl<'a, T> MutAccessor<'a, T> for PackedStrategy
+where
+    T: PackedState + 'a,
+{
+    type Data = T::View<&'a mut [u8]>;
+
+    #[inline(always)]
+    fn access_mut(data: &'a mut [u8]) -> Result<Self::Data, ProgramError> {
+        T::view(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_fields() {
+        let mut buffer = [0xFF; 13];
+
+        Some(7u64).pack(&mut buffer[..12]);
+        true.pack(&mut buffer[12..]);
+        assert_eq!(buffer[..4], [1, 0, 0, 0]);
+        assert_eq!(Option::<u64>::unpack(&buffer[..12]), Ok(Some(7)));
+        assert_eq!(bool::unpack(&buffer[12..]), Ok(true));
+
+        None::<u64>.pack(&mut buffer[..12]);
+        assert_eq!(buffer[..12], [0; 12]);
+        assert_eq!(Option::<u64>::unpack(&buffer[..12]), Ok(None));
+
+        buffer[12] = 2;
+        assert_eq!(
+            bool::unpack(&buffer[12..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            u64::unpack(&buffer[..4]),
+            Err(ErrorCode::InvalidDataLength.into())
+        );
+    }
+}
@@ -21,6 +21,7 @@ where
     &'a T: SchemaRead<'a, C, Dst = &'a T>,
 {
     type Data = &'a T;
+    type Read = &'a T;
 
     #[inline(always)]
     fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
@@ -28,7 +29,7 @@ where
     }
 
     #[inline(always)]
-    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError> {
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
         <&T as SchemaRead<'a, C>>::get(data).map_err(|_| ProgramError::BorshIoError)
     }
 }
@@ -39,6 +40,7 @@ where
     T: SchemaRead<'a, C, Dst = T> + 'a,
 {
     type Data = T;
+    type Read = T;
 
     #[inline(always)]
     fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
@@ -46,7 +48,7 @@ where
     }
 
     #[inline(always)]
-    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError> {
+    fn read(data: &mut &'a [u8]) -> Result<Self::Read, ProgramError> {
         <T as SchemaRead<'a, C>>::get(data).map_err(|_| ProgramError::BorshIoError)
     }
 }
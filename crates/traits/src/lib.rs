@@ -26,3 +26,18 @@ pub trait Discriminator {
     /// The discriminator bytes.
     const DISCRIMINATOR: &'static [u8];
 }
+
+/// Trait for account states whose layout can be migrated from a previous version.
+pub trait Migrate: Discriminator {
+    /// The layout this state is migrated from.
+    type Previous: DataStrategy;
+
+    /// The discriminator of accounts still stored with the previous layout.
+    const PREVIOUS_DISCRIMINATOR: &'static [u8];
+}
+
+/// Trait for account states followed by a dynamic number of fixed-size entries.
+pub trait Tail {
+    /// The type of the entries stored after the state.
+    type Entry;
+}
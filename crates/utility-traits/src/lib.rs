@@ -3,6 +3,10 @@
 mod close;
 mod create;
 mod lamport;
+mod migrate;
 mod system;
+#[cfg(test)]
+mod test_utils;
+mod write;
 
-pub use {close::*, create::*, lamport::*, system::*};
+pub use {close::*, create::*, lamport::*, migrate::*, system::*, write::*};
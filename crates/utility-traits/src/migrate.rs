@@ -0,0 +1,217 @@
+use {
+    pinocchio::{sysvars::rent::Rent, AccountView},
+    pinocchio_system::instructions::Transfer,
+    typhoon_accounts::{
+        discriminator_matches, Account, Mut, SignerAccount, VersionedAccount, WritableAccount,
+    },
+    typhoon_errors::Error,
+    typhoon_traits::{Accessor, DataStrategy, Migrate, MutAccessor},
+};
+
+/// Migrates an account stored with the previous layout of `T` to the current one.
+///
+/// The account is resized to the current layout, topped up to stay rent exempt by
+/// the `payer`, a signer owned by the system program, converted with `T::from` and
+/// its discriminator is rewritten. Accounts already using the current layout are left
+/// untouched.
+///
+/// Only the version directly before `T` can be migrated, accounts of older versions must be
+/// migrated through each version in between first.
+pub trait MigrateAccount<'a, T>
+where
+    Self: Sized + Into<&'a AccountView>,
+    T: Migrate + DataStrategy + From<T::Previous> + 'a,
+    T::Previous: Copy,
+    <T as DataStrategy>::Strategy: for<'b> MutAccessor<'b, T, Data = &'b mut T>,
+    <T::Previous as DataStrategy>::Strategy:
+        for<'b> Accessor<'b, T::Previous, Data = &'b T::Previous>,
+{
+    #[inline]
+    fn migrate(
+        self,
+        rent: &Rent,
+        payer: &(impl WritableAccount + SignerAccount),
+    ) -> Result<Mut<Account<'a, T>>, Error> {
+        let info = self.into();
+
+        if discriminator_matches::<T>(info) {
+            return Ok(Mut::from_raw_info(info));
+        }
+
+        let previous = {
+            let data = info.try_borrow()?;
+            *<<T::Previous as DataStrategy>::Strategy as Accessor<'_, T::Previous>>::access(
+                &data[T::PREVIOUS_DISCRIMINATOR.len()..],
+            )?
+        };
+
+        let space = T::DISCRIMINATOR.len() + core::mem::size_of::<T>();
+        let required_lamports = rent
+            .try_minimum_balance(space)?
+            .saturating_sub(info.lamports());
+
+        if required_lamports > 0 {
+            Transfer {
+                from: payer.as_ref(),
+                to: info,
+                lamports: required_lamports,
+            }
+            .invoke()?;
+        }
+
+        info.resize(space)?;
+
+        {
+            let mut data = info.try_borrow_mut()?;
+            let (discriminator, state) = data.split_at_mut(T::DISCRIMINATOR.len());
+            discriminator.copy_from_slice(T::DISCRIMINATOR);
+            *<<T as DataStrategy>::Strategy as MutAccessor<'_, T>>::access_mut(state)? =
+                T::from(previous);
+        }
+
+        Ok(Mut::from_raw_info(info))
+    }
+}
+
+impl<'a, T> MigrateAccount<'a, T> for Mut<VersionedAccount<'a, T>>
+where
+    T: Migrate + DataStrategy + From<T::Previous> + 'a,
+    T::Previous: Copy,
+    <T as DataStrategy>::Strategy: for<'b> MutAccessor<'b, T, Data = &'b mut T>,
+    <T::Previous as DataStrategy>::Strategy:
+        for<'b> Accessor<'b, T::Previous, Data = &'b T::Previous>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        crate::test_utils::{account_memory, rent, view, OWNER},
+        bytemuck::{Pod, Zeroable},
+        pinocchio::{error::ProgramError, Address},
+        std::vec::Vec,
+        typhoon_accounts::{FromAccountInfo, ReadableAccountData, Signer},
+        typhoon_errors::ErrorCode,
+        typhoon_traits::{BytemuckStrategy, CheckOwner, Discriminator},
+    };
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct CounterV1 {
+        count: u32,
+    }
+
+    // SAFETY: `CounterV1` is `#[repr(C)]` without padding.
+    unsafe impl Zeroable for CounterV1 {}
+    unsafe impl Pod for CounterV1 {}
+
+    impl DataStrategy for CounterV1 {
+        type Strategy = BytemuckStrategy;
+    }
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Counter {
+        count: u64,
+    }
+
+    // SAFETY: `Counter` is `#[repr(C)]` without padding.
+    unsafe impl Zeroable for Counter {}
+    unsafe impl Pod for Counter {}
+
+    impl Discriminator for Counter {
+        const DISCRIMINATOR: &'static [u8] = &[9, 9, 9, 9, 2, 0, 0, 0];
+    }
+
+    impl DataStrategy for Counter {
+        type Strategy = BytemuckStrategy;
+    }
+
+    impl CheckOwner for Counter {
+        fn owned_by(owner: &Address) -> bool {
+            *owner == OWNER
+        }
+    }
+
+    impl Migrate for Counter {
+        type Previous = CounterV1;
+
+        const PREVIOUS_DISCRIMINATOR: &'static [u8] = &[9, 9, 9, 9, 1, 0, 0, 0];
+    }
+
+    impl From<CounterV1> for Counter {
+        fn from(value: CounterV1) -> Self {
+            Counter {
+                count: value.count as u64 * 10,
+            }
+        }
+    }
+
+    fn counter_data(discriminator: &[u8], state: &[u8]) -> Vec<u8> {
+        [discriminator, state].concat()
+    }
+
+    fn versioned(info: &AccountView) -> Result<Mut<VersionedAccount<'_, Counter>>, ProgramError> {
+        Mut::try_from_info(info).map_err(ProgramError::from)
+    }
+
+    fn is_current(info: &AccountView) -> bool {
+        VersionedAccount::<Counter>::try_from_info(info)
+            .map_err(ProgramError::from)
+            .unwrap()
+            .is_current()
+    }
+
+    #[test]
+    fn test_migrate_previous_layout() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(12).unwrap();
+        let data = counter_data(Counter::PREVIOUS_DISCRIMINATOR, &7u32.to_le_bytes());
+        let mut memory = account_memory(&data, balance);
+        let mut payer_memory = account_memory(&[], 1_000_000_000);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let payer = Mut::<Signer>::try_from_info(&payer_info)
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        // The previous layout is accepted.
+        assert!(!is_current(&info));
+        let counter = versioned(&info)
+            .unwrap()
+            .migrate(&rent, &payer)
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        // The account is resized to the current layout, the top-up being a system transfer
+        // only executed on-chain.
+        assert_eq!(info.data_len(), 16);
+        assert_eq!(info.lamports(), balance);
+        assert_eq!(&info.try_borrow().unwrap()[..8], Counter::DISCRIMINATOR);
+        assert_eq!(counter.data().unwrap().count, 70);
+
+        // Migrated accounts are read with the current layout and left untouched.
+        assert!(is_current(&info));
+        let counter = versioned(&info)
+            .unwrap()
+            .migrate(&rent, &payer)
+            .map_err(ProgramError::from)
+            .unwrap();
+        assert_eq!(counter.data().unwrap().count, 70);
+        assert_eq!(info.data_len(), 16);
+    }
+
+    #[test]
+    fn test_versioned_account_rejects_unknown_version() {
+        let data = counter_data(&[9, 9, 9, 9, 3, 0, 0, 0], &7u64.to_le_bytes());
+        let mut memory = account_memory(&data, 1_000_000);
+        let info = view(&mut memory);
+
+        assert_eq!(
+            versioned(&info).err(),
+            Some(ErrorCode::AccountDiscriminatorMismatch.into())
+        );
+    }
+}
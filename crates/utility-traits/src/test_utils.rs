@@ -0,0 +1,48 @@
+extern crate std;
+
+use {
+    pinocchio::{
+        account::{RuntimeAccount, NOT_BORROWED},
+        sysvars::rent::Rent,
+        AccountView, Address,
+    },
+    std::{vec, vec::Vec},
+};
+
+/// Owner of the accounts built by `account_memory`.
+pub const OWNER: Address = Address::new_from_array([2; 32]);
+
+/// Builds the memory of a signer account as laid out by the runtime, with room to grow.
+pub fn account_memory(data: &[u8], lamports: u64) -> Vec<u64> {
+    let header = core::mem::size_of::<RuntimeAccount>();
+    let mut memory = vec![0u64; (header + data.len() + 10 * 1024).div_ceil(8)];
+    let raw = memory.as_mut_ptr() as *mut RuntimeAccount;
+    // SAFETY: The memory is aligned and large enough for the header and the data.
+    unsafe {
+        raw.write(RuntimeAccount {
+            borrow_state: NOT_BORROWED,
+            is_signer: 1,
+            is_writable: 1,
+            executable: 0,
+            resize_delta: 0,
+            address: Address::new_from_array([1; 32]),
+            owner: OWNER,
+            lamports,
+            data_len: data.len() as u64,
+        });
+        core::ptr::copy_nonoverlapping(data.as_ptr(), (raw as *mut u8).add(header), data.len());
+    }
+    memory
+}
+
+pub fn view(memory: &mut [u64]) -> AccountView {
+    // SAFETY: The memory was built by `account_memory`.
+    unsafe { AccountView::new_unchecked(memory.as_mut_ptr() as *mut RuntimeAccount) }
+}
+
+pub fn rent() -> Rent {
+    let bytes = [3480u64, 2f64.to_bits()];
+    Rent::from_bytes(bytemuck::bytes_of(&bytes))
+        .unwrap()
+        .clone()
+}
@@ -0,0 +1,454 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{error::ProgramError, sysvars::rent::Rent},
+    pinocchio_system::instructions::Transfer,
+    typhoon_accounts::{ReadableAccountData, SignerAccount, WritableAccount, WritableAccountData},
+    typhoon_errors::Error,
+    typhoon_traits::{Accessor, DataStrategy, Discriminator, Tail, Write},
+};
+
+/// Resizes `account` to `space` while keeping it rent exempt: the `payer` tops it up
+/// when it grows and gets the rent of the freed space back when it shrinks.
+#[inline]
+fn resize_rent_exempt(
+    account: &impl WritableAccount,
+    space: usize,
+    rent: &Rent,
+    payer: &(impl WritableAccount + SignerAccount),
+) -> Result<(), Error> {
+    let info = account.as_ref();
+    let current_space = info.data_len();
+    if space == current_space {
+        return Ok(());
+    }
+
+    let lamports = info.lamports();
+    let minimum_balance = rent.try_minimum_balance(space)?;
+
+    if minimum_balance > lamports {
+        Transfer {
+            from: payer.as_ref(),
+            to: info,
+            lamports: minimum_balance - lamports,
+        }
+        .invoke()?;
+    } else {
+        // Only the rent of the freed space is returned, other lamports stay in the account.
+        let refund = rent
+            .try_minimum_balance(current_space)?
+            .saturating_sub(minimum_balance)
+            .min(lamports - minimum_balance);
+        account.set_lamports(lamports - refund);
+        payer.set_lamports(
+            payer
+                .lamports()
+                .checked_add(refund)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        );
+    }
+
+    account.resize(space)
+}
+
+/// Persists account states decoded by value, e.g. with `BorshStrategy`.
+///
+/// The state is serialized with the `Write` implementation of its strategy. When the
+/// encoded size changes, the account is resized and stays rent exempt: the `payer`
+/// (a signer owned by the system program) tops it up when it grows and gets the rent of the
+/// freed space back when it shrinks.
+pub trait WriteAccountData: WritableAccountData + Sized {
+    #[inline]
+    fn set_data(
+        &self,
+        data: &Self::Data,
+        rent: &Rent,
+        payer: &(impl WritableAccount + SignerAccount),
+    ) -> Result<(), Error>
+    where
+        <Self::Data as DataStrategy>::Strategy: Write<Self::Data>,
+    {
+        let offset = Self::Data::DISCRIMINATOR.len();
+        let space = offset + <Self::Data as DataStrategy>::Strategy::size(data)?;
+        resize_rent_exempt(self, space, rent, payer)?;
+
+        <Self::Data as DataStrategy>::Strategy::write_into(
+            &mut self.raw_mut_data()?[offset..],
+            data,
+        )?;
+
+        Ok(())
+    }
+
+    /// Decodes the state, applies `f` to it and writes it back with `set_data`.
+    #[inline]
+    fn with_data_mut<R>(
+        &self,
+        rent: &Rent,
+        payer: &(impl WritableAccount + SignerAccount),
+        f: impl FnOnce(&mut Self::Data) -> R,
+    ) -> Result<R, Error>
+    where
+        <Self::Data as DataStrategy>::Strategy:
+            Write<Self::Data> + for<'a> Accessor<'a, Self::Data, Data = Self::Data>,
+    {
+        let mut data = self.data_owned()?;
+        let result = f(&mut data);
+        self.set_data(&data, rent, payer)?;
+
+        Ok(result)
+    }
+}
+
+impl<T> WriteAccountData for T where T: WritableAccountData {}
+
+/// Appends and removes the entries of accounts declared with `#[tail]`, keeping them
+/// rent exempt like [`WriteAccountData::set_data`].
+pub trait TailAccountData: WritableAccountData + Sized
+where
+    Self::Data: Tail + NoUninit + AnyBitPattern,
+    <Self::Data as Tail>::Entry: NoUninit + AnyBitPattern,
+{
+    /// Appends an entry after the existing ones, growing the account topped up by the `payer`.
+    #[inline]
+    fn push_entry(
+        &self,
+        entry: <Self::Data as Tail>::Entry,
+        rent: &Rent,
+        payer: &(impl WritableAccount + SignerAccount),
+    ) -> Result<(), Error> {
+        let offset = {
+            let data = self.tail_data_mut()?;
+            Self::Data::DISCRIMINATOR.len()
+                + core::mem::size_of::<Self::Data>()
+                + data.len() * core::mem::size_of::<<Self::Data as Tail>::Entry>()
+        };
+
+        resize_rent_exempt(
+            self,
+            offset + core::mem::size_of::<<Self::Data as Tail>::Entry>(),
+            rent,
+            payer,
+        )?;
+        self.raw_mut_data()?[offset..].copy_from_slice(bytemuck::bytes_of(&entry));
+
+        Ok(())
+    }
+
+    /// Removes the last entry and shrinks the account, returning the rent of the freed space
+    /// to the `payer`, or `None` if there are no entries.
+    #[inline]
+    fn pop_entry(
+        &self,
+        rent: &Rent,
+        payer: &(impl WritableAccount + SignerAccount),
+    ) -> Result<Option<<Self::Data as Tail>::Entry>, Error> {
+        let entry = {
+            let data = self.tail_data_mut()?;
+            match data.split().1.last() {
+                Some(entry) => *entry,
+                None => return Ok(None),
+            }
+        };
+
+        resize_rent_exempt(
+            self,
+            self.as_ref().data_len() - core::mem::size_of::<<Self::Data as Tail>::Entry>(),
+            rent,
+            payer,
+        )?;
+
+        Ok(Some(entry))
+    }
+}
+
+impl<T> TailAccountData for T
+where
+    T: WritableAccountData,
+    T::Data: Tail + NoUninit + AnyBitPattern,
+    <T::Data as Tail>::Entry: NoUninit + AnyBitPattern,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        crate::test_utils::{account_memory, rent, view},
+        bytemuck::{Pod, Zeroable},
+        pinocchio::AccountView,
+        std::vec::Vec,
+        typhoon_accounts::{Account, FromAccountInfo, Mut, Signer},
+        typhoon_traits::BytemuckStrategy,
+        wincode::io::Writer,
+    };
+
+    struct Note {
+        len: usize,
+        bytes: [u8; 32],
+    }
+
+    impl Note {
+        fn new(content: &[u8]) -> Self {
+            let mut bytes = [0; 32];
+            bytes[..content.len()].copy_from_slice(content);
+            Note {
+                len: content.len(),
+                bytes,
+            }
+        }
+    }
+
+    struct NoteStrategy;
+
+    impl Discriminator for Note {
+        const DISCRIMINATOR: &'static [u8] = &[7];
+    }
+
+    impl DataStrategy for Note {
+        type Strategy = NoteStrategy;
+    }
+
+    impl Accessor<'_, Note> for NoteStrategy {
+        type Data = Note;
+        type Read = Note;
+
+        fn access(data: &[u8]) -> Result<Self::Data, ProgramError> {
+            Ok(Note::new(data))
+        }
+
+        fn read(data: &mut &[u8]) -> Result<Self::Read, ProgramError> {
+            Self::access(core::mem::take(data))
+        }
+    }
+
+    impl Write<Note> for NoteStrategy {
+        fn size(data: &Note) -> Result<usize, ProgramError> {
+            Ok(data.len)
+        }
+
+        fn write_into(mut writer: impl Writer, data: &Note) -> Result<(), ProgramError> {
+            writer
+                .write(&data.bytes[..data.len])
+                .map_err(|_| ProgramError::BorshIoError)
+        }
+    }
+
+    fn payer(info: &AccountView) -> Mut<Signer<'_>> {
+        Mut::try_from_info(info)
+            .map_err(ProgramError::from)
+            .unwrap()
+    }
+
+    fn note_data(content: &[u8]) -> Vec<u8> {
+        [Note::DISCRIMINATOR, content].concat()
+    }
+
+    #[test]
+    fn test_set_data_grows() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(5).unwrap();
+        let mut memory = account_memory(&note_data(b"abcd"), balance);
+        let mut payer_memory = account_memory(&[], 1_000_000_000);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let account = Mut::<Account<Note>>::from_raw_info(&info);
+        let payer = payer(&payer_info);
+
+        account
+            .set_data(&Note::new(b"abcdefgh"), &rent, &payer)
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        assert_eq!(info.data_len(), 9);
+        assert_eq!(account.data_owned().unwrap().bytes[..8], *b"abcdefgh");
+        // The top-up is a system transfer, which is only executed on-chain.
+        assert_eq!(info.lamports(), balance);
+    }
+
+    #[test]
+    fn test_set_data_shrinks() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(9).unwrap();
+        let mut memory = account_memory(&note_data(b"abcdefgh"), balance + 100);
+        let mut payer_memory = account_memory(&[], 1_000);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let account = Mut::<Account<Note>>::from_raw_info(&info);
+        let payer = payer(&payer_info);
+
+        account
+            .set_data(&Note::new(b"ab"), &rent, &payer)
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        // Only the rent of the freed space is refunded, the extra lamports stay.
+        let refund = balance - rent.try_minimum_balance(3).unwrap();
+        assert_eq!(info.data_len(), 3);
+        assert_eq!(account.data_owned().unwrap().bytes[..2], *b"ab");
+        assert_eq!(info.lamports(), balance + 100 - refund);
+        assert_eq!(payer_info.lamports(), 1_000 + refund);
+    }
+
+    #[test]
+    fn test_set_data_refund_overflow() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(9).unwrap();
+        let mut memory = account_memory(&note_data(b"abcdefgh"), balance);
+        let mut payer_memory = account_memory(&[], u64::MAX);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let account = Mut::<Account<Note>>::from_raw_info(&info);
+        let payer = payer(&payer_info);
+
+        assert_eq!(
+            account
+                .set_data(&Note::new(b"ab"), &rent, &payer)
+                .err()
+                .map(ProgramError::from),
+            Some(ProgramError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_with_data_mut_same_size() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(5).unwrap();
+        let mut memory = account_memory(&note_data(b"abcd"), balance + 100);
+        let mut payer_memory = account_memory(&[], 1_000);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let account = Mut::<Account<Note>>::from_raw_info(&info);
+        let payer = payer(&payer_info);
+
+        let len = account
+            .with_data_mut(&rent, &payer, |note| {
+                note.bytes[..4].copy_from_slice(b"wxyz");
+                note.len
+            })
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        assert_eq!(len, 4);
+        assert_eq!(info.data_len(), 5);
+        assert_eq!(account.data_owned().unwrap().bytes[..4], *b"wxyz");
+        assert_eq!(info.lamports(), balance + 100);
+        assert_eq!(payer_info.lamports(), 1_000);
+    }
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Book {
+        sequence: u64,
+        entries: [u64; 0],
+    }
+
+    // SAFETY: `Book` is `#[repr(C)]` without padding.
+    unsafe impl Zeroable for Book {}
+    unsafe impl Pod for Book {}
+
+    impl Discriminator for Book {
+        const DISCRIMINATOR: &'static [u8] = &[3; 8];
+    }
+
+    impl DataStrategy for Book {
+        type Strategy = BytemuckStrategy;
+    }
+
+    impl Tail for Book {
+        type Entry = u64;
+    }
+
+    fn book_data(entries: &[u64]) -> Vec<u8> {
+        let mut data = [Book::DISCRIMINATOR, &1u64.to_le_bytes()].concat();
+        entries
+            .iter()
+            .for_each(|entry| data.extend_from_slice(&entry.to_le_bytes()));
+        data
+    }
+
+    fn entries(account: &Mut<Account<Book>>) -> Vec<u64> {
+        account.tail_data().unwrap().split().1.to_vec()
+    }
+
+    #[test]
+    fn test_tail_len_follows_data_len() {
+        let mut memory = account_memory(&book_data(&[4, 5]), 1_000_000);
+        let info = view(&mut memory);
+        let account = Mut::<Account<Book>>::from_raw_info(&info);
+
+        let tail = account.tail_data().unwrap();
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail.split().0.sequence, 1);
+        assert_eq!(tail.split().1, [4, 5]);
+        drop(tail);
+
+        info.resize(info.data_len() - 3).unwrap();
+        assert_eq!(
+            account.tail_data().err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        info.resize(12).unwrap();
+        assert_eq!(
+            account.tail_data().err(),
+            Some(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_push_entry_grows() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(24).unwrap();
+        let mut memory = account_memory(&book_data(&[4, 5]), balance);
+        let mut payer_memory = account_memory(&[], 1_000_000_000);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let account = Mut::<Account<Book>>::from_raw_info(&info);
+
+        account
+            .push_entry(6, &rent, &payer(&payer_info))
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        assert_eq!(info.data_len(), 40);
+        assert_eq!(entries(&account), [4, 5, 6]);
+        // The top-up is a system transfer, which is only executed on-chain.
+        assert_eq!(info.lamports(), balance);
+    }
+
+    #[test]
+    fn test_pop_entry_shrinks() {
+        let rent = rent();
+        let balance = rent.try_minimum_balance(32).unwrap();
+        let mut memory = account_memory(&book_data(&[4, 5]), balance);
+        let mut payer_memory = account_memory(&[], 1_000);
+        let (info, payer_info) = (view(&mut memory), view(&mut payer_memory));
+        let account = Mut::<Account<Book>>::from_raw_info(&info);
+        let payer = payer(&payer_info);
+
+        let entry = account
+            .pop_entry(&rent, &payer)
+            .map_err(ProgramError::from)
+            .unwrap();
+
+        let refund = balance - rent.try_minimum_balance(24).unwrap();
+        assert_eq!(entry, Some(5));
+        assert_eq!(info.data_len(), 24);
+        assert_eq!(entries(&account), [4]);
+        assert_eq!(info.lamports(), balance - refund);
+        assert_eq!(payer_info.lamports(), 1_000 + refund);
+
+        assert_eq!(
+            account
+                .pop_entry(&rent, &payer)
+                .map_err(ProgramError::from)
+                .unwrap(),
+            Some(4)
+        );
+        assert_eq!(
+            account
+                .pop_entry(&rent, &payer)
+                .map_err(ProgramError::from)
+                .unwrap(),
+            None
+        );
+        assert_eq!(info.data_len(), 16);
+        assert_eq!(info.lamports(), rent.try_minimum_balance(16).unwrap());
+    }
+}
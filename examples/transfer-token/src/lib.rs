@@ -64,16 +64,15 @@ pub struct MintFromEscrow {
 }
 
 pub fn mint_from_escrow(ctx: MintFromEscrow) -> ProgramResult {
-    MintTo {
-        mint: ctx.mint.as_ref(),
-        account: ctx.token_account.as_ref(),
-        mint_authority: ctx.escrow.as_ref(),
-        amount: ctx.args.amount,
-    }
-    .invoke_signed(&[CpiSigner::from(&seeds!(
-        b"escrow".as_ref(),
-        &[ctx.bumps.escrow]
-    ))])?;
+    ctx.escrow_signer(|signer| {
+        MintTo {
+            mint: ctx.mint.as_ref(),
+            account: ctx.token_account.as_ref(),
+            mint_authority: ctx.escrow.as_ref(),
+            amount: ctx.args.amount,
+        }
+        .invoke_signed(&[signer])?;
 
-    Ok(())
+        Ok(())
+    })
 }